@@ -92,7 +92,9 @@ use std::fmt::Debug;
 use std::hash::Hash;
 use std::iter::FromIterator;
 use std::vec;
-use theon::adjunct::Map;
+use theon::adjunct::{Adjunct, IntoItems, Map};
+use theon::ops::Cross;
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
 use thiserror::Error;
 use typenum::{self, NonZero, Unsigned as _, U3, U4};
 
@@ -100,6 +102,9 @@ use crate::buffer::builder::BufferBuilder;
 use crate::builder::{Buildable, MeshBuilder};
 use crate::constant::{Constant, ToType, TypeOf};
 use crate::encoding::{FaceDecoder, FromEncoding, VertexDecoder};
+use crate::geometry::bvh::Bvh;
+use crate::geometry::kdtree::KdTree;
+use crate::geometry::tangent::{self, Corner, Tangent};
 use crate::geometry::{FromGeometry, IntoGeometry};
 use crate::index::{
     BufferOf, Flat, Flat3, Flat4, FromIndexer, Grouping, HashIndexer, IndexBuffer, IndexOf,
@@ -354,6 +359,43 @@ where
     pub fn as_vertex_slice(&self) -> &[G] {
         self.vertices.as_slice()
     }
+
+    /// Builds a $k$-d tree over the vertices in the buffer.
+    ///
+    /// The given function is used to read the position of each vertex. The
+    /// data associated with each point in the tree is the index of its vertex.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::buffer::MeshBuffer4;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// let buffer: MeshBuffer4<usize, Point3<f64>> = Cube::new()
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .collect();
+    /// let tree = buffer.kdtree_with(|position| *position);
+    ///
+    /// let neighbors = tree.nearest(Point3::new(1.0, 1.0, 1.0), 1);
+    /// let index = neighbors[0].data;
+    /// assert_eq!(Point3::new(0.5, 0.5, 0.5), buffer.as_vertex_slice()[index]);
+    /// ```
+    pub fn kdtree_with<S, F>(&self, f: F) -> KdTree<usize, S>
+    where
+        S: EuclideanSpace,
+        Vector<S>: Adjunct<Item = Scalar<S>> + IntoItems,
+        F: FnMut(&G) -> S,
+    {
+        KdTree::from_points(self.vertices.iter().map(f).enumerate())
+    }
 }
 
 /// Exposes a [`MeshBuilder`] that can be used to construct a [`MeshBuffer`]
@@ -547,6 +589,142 @@ where
     }
 }
 
+impl<N, G> MeshBuffer<Trigon<N>, G>
+where
+    N: Copy + Integer + NumCast + Unsigned,
+    Trigon<N>: Grouping<Group = Trigon<N>>,
+{
+    /// Computes tangent frames for the corners of each triangle in a
+    /// `MeshBuffer`.
+    ///
+    /// The given function reads the position, normal, and texture coordinates
+    /// of a vertex. The returned frames are ordered like the index buffer. See
+    /// the [`tangent`] module.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::{Point3, Vector3};
+    /// use plexus::buffer::MeshBuffer3;
+    /// use plexus::geometry::tangent::Corner;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// pub struct Vertex {
+    ///     pub position: Point3<f64>,
+    ///     pub uv: (f64, f64),
+    /// }
+    ///
+    /// let buffer = MeshBuffer3::<usize, _>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2)],
+    ///     vec![
+    ///         Vertex {
+    ///             position: Point3::new(0.0, 0.0, 0.0),
+    ///             uv: (0.0, 0.0),
+    ///         },
+    ///         Vertex {
+    ///             position: Point3::new(1.0, 0.0, 0.0),
+    ///             uv: (1.0, 0.0),
+    ///         },
+    ///         Vertex {
+    ///             position: Point3::new(0.0, 1.0, 0.0),
+    ///             uv: (0.0, 1.0),
+    ///         },
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// let tangents = buffer.tangents_with(|vertex| Corner {
+    ///     position: vertex.position,
+    ///     normal: Vector3::z(),
+    ///     uv: vertex.uv,
+    /// });
+    /// for frame in tangents[0].into_array() {
+    ///     assert_eq!(Vector3::x(), frame.tangent);
+    ///     assert_eq!(1.0, frame.sign);
+    /// }
+    /// ```
+    ///
+    /// [`tangent`]: crate::geometry::tangent
+    pub fn tangents_with<S, F>(&self, mut f: F) -> Vec<Trigon<Tangent<S>>>
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<S>: Cross<Output = Vector<S>>,
+        F: FnMut(&G) -> Corner<S>,
+    {
+        let triangles = self
+            .indices
+            .iter()
+            .map(|trigon| {
+                trigon
+                    .into_array()
+                    .map(|index| {
+                        let index = <usize as NumCast>::from(index).expect("index overflow");
+                        f(&self.vertices[index])
+                    })
+            })
+            .collect::<Vec<_>>();
+        tangent::tangents(triangles)
+            .into_iter()
+            .map(Trigon::from)
+            .collect()
+    }
+
+    /// Builds a bounding volume hierarchy over the triangles in the buffer.
+    ///
+    /// The given function is used to read the position of each vertex. The
+    /// data associated with each triangle is its index in the index buffer.
+    /// The barycentric coordinates of ray hits correspond to the vertices of
+    /// the indexed triangle in order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::{Point3, Vector3};
+    /// use plexus::buffer::MeshBuffer3;
+    /// use plexus::geometry::{Ray, Unit};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let buffer = MeshBuffer3::<usize, _>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2)],
+    ///     vec![
+    ///         Point3::new(0.0, 0.0, 0.0),
+    ///         Point3::new(1.0, 0.0, 0.0),
+    ///         Point3::new(0.0, 1.0, 0.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// let bvh = buffer.bvh_with(|position| *position);
+    ///
+    /// let ray = Ray {
+    ///     origin: Point3::new(0.5, 0.25, 1.0),
+    ///     direction: Unit::try_from_inner(-Vector3::z()).unwrap(),
+    /// };
+    /// let hit = bvh.cast_ray(&ray).unwrap();
+    /// assert_eq!(0, hit.data);
+    /// assert_eq!((0.25, 0.5, 0.25), hit.barycentric);
+    /// ```
+    pub fn bvh_with<S, F>(&self, mut f: F) -> Bvh<usize, S>
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+        F: FnMut(&G) -> S,
+    {
+        Bvh::from_triangles(self.indices.iter().enumerate().map(|(n, trigon)| {
+            let triangle = trigon.into_array().map(|index| {
+                let index = <usize as NumCast>::from(index).expect("index overflow");
+                f(&self.vertices[index])
+            });
+            (n, triangle)
+        }))
+    }
+}
+
 impl<P, Q, T, R, N, G> From<P> for MeshBuffer<R, G>
 where
     P: IntoIndexed<N, Indexed = Q> + Polygonal,
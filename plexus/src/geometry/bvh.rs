@@ -0,0 +1,700 @@
+//! Bounding volume hierarchies.
+//!
+//! This module provides a bounding volume hierarchy (BVH) over triangles in
+//! three-dimensional Euclidean spaces. Each triangle is associated with
+//! arbitrary data, such as the key of the face from which it is derived, which
+//! is returned by queries. Hierarchies are built from the axis-aligned bounding
+//! boxes of triangles by recursively splitting them at the median of their
+//! centroids along the longest axis. Hierarchies support ray casting, closest
+//! point, and overlap queries.
+//!
+//! See [`MeshGraph::bvh`] and [`MeshBuffer::bvh_with`].
+//!
+//! [`MeshBuffer::bvh_with`]: crate::buffer::MeshBuffer::bvh_with
+//! [`MeshGraph::bvh`]: crate::graph::MeshGraph::bvh
+
+use num::{One, Zero};
+use std::cmp::Ordering;
+use theon::ops::{Cross, Dot};
+use theon::query::Ray;
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use typenum::U3;
+
+// Maximum number of triangles in a leaf.
+const LEAF_SIZE: usize = 4;
+
+/// Intersection of a ray with a triangle in a [`Bvh`].
+#[derive(Clone, Copy)]
+pub struct RayHit<T, S>
+where
+    S: EuclideanSpace,
+{
+    /// Data associated with the intersected triangle.
+    pub data: T,
+    /// Distance from the origin of the ray to the point of intersection.
+    pub distance: Scalar<S>,
+    /// Barycentric coordinates of the point of intersection with respect to
+    /// the vertices of the intersected triangle.
+    pub barycentric: (Scalar<S>, Scalar<S>, Scalar<S>),
+}
+
+/// Closest point on a triangle in a [`Bvh`] to a query point.
+#[derive(Clone, Copy)]
+pub struct ClosestPoint<T, S>
+where
+    S: EuclideanSpace,
+{
+    /// Data associated with the nearest triangle.
+    pub data: T,
+    /// The closest point on the nearest triangle.
+    pub point: S,
+    /// Distance from the query point to the closest point.
+    pub distance: Scalar<S>,
+    /// Barycentric coordinates of the closest point with respect to the
+    /// vertices of the nearest triangle.
+    ///
+    /// Coordinates are exactly zero if the closest point lies on an edge or
+    /// vertex of the triangle opposite to the corresponding vertex.
+    pub barycentric: (Scalar<S>, Scalar<S>, Scalar<S>),
+}
+
+#[derive(Clone, Copy)]
+struct Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    lower: [Scalar<S>; 3],
+    upper: [Scalar<S>; 3],
+}
+
+impl<S> Bounds<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    fn from_triangle(triangle: &[S; 3]) -> Self {
+        let [a, b, c] = triangle.map(xyz);
+        let mut bounds = Bounds { lower: a, upper: a };
+        bounds.extend(&b);
+        bounds.extend(&c);
+        bounds
+    }
+
+    fn extend(&mut self, point: &[Scalar<S>; 3]) {
+        for ((lower, upper), x) in self
+            .lower
+            .iter_mut()
+            .zip(self.upper.iter_mut())
+            .zip(point.iter())
+        {
+            if *x < *lower {
+                *lower = *x;
+            }
+            if *x > *upper {
+                *upper = *x;
+            }
+        }
+    }
+
+    fn union(mut self, other: &Self) -> Self {
+        self.extend(&other.lower);
+        self.extend(&other.upper);
+        self
+    }
+
+    fn is_overlapping(&self, other: &Self) -> bool {
+        self.lower
+            .iter()
+            .zip(self.upper.iter())
+            .zip(other.lower.iter().zip(other.upper.iter()))
+            .all(|((lower, upper), (other_lower, other_upper))| {
+                lower <= other_upper && other_lower <= upper
+            })
+    }
+
+    fn longest_axis(&self) -> usize {
+        let extent = [
+            self.upper[0] - self.lower[0],
+            self.upper[1] - self.lower[1],
+            self.upper[2] - self.lower[2],
+        ];
+        (1..3).fold(0, |axis, n| if extent[n] > extent[axis] { n } else { axis })
+    }
+
+    // Gets the squared distance from a point to the bounds. The distance is
+    // zero if the point is contained by the bounds.
+    fn squared_distance(&self, point: &[Scalar<S>; 3]) -> Scalar<S> {
+        point
+            .iter()
+            .zip(self.lower.iter())
+            .zip(self.upper.iter())
+            .map(|((x, lower), upper)| {
+                if *x < *lower {
+                    *lower - *x
+                }
+                else if *x > *upper {
+                    *x - *upper
+                }
+                else {
+                    Zero::zero()
+                }
+            })
+            .fold(Zero::zero(), |sum: Scalar<S>, d| sum + (d * d))
+    }
+
+    // Gets the distance along the ray at which it enters the bounds, if any.
+    // The direction of the ray is not inverted so that this does not depend on
+    // infinities, which some scalar types cannot represent.
+    fn intersect_ray(
+        &self,
+        origin: &[Scalar<S>; 3],
+        direction: &[Scalar<S>; 3],
+    ) -> Option<Scalar<S>> {
+        let mut near = Scalar::<S>::zero();
+        let mut far: Option<Scalar<S>> = None;
+        for (((o, d), lower), upper) in origin
+            .iter()
+            .zip(direction.iter())
+            .zip(self.lower.iter())
+            .zip(self.upper.iter())
+        {
+            let (o, d, lower, upper) = (*o, *d, *lower, *upper);
+            if d.is_zero() {
+                if o < lower || o > upper {
+                    return None;
+                }
+                continue;
+            }
+            let (mut t0, mut t1) = ((lower - o) / d, (upper - o) / d);
+            if t0 > t1 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+            if t0 > near {
+                near = t0;
+            }
+            far = Some(match far {
+                Some(far) if far < t1 => far,
+                _ => t1,
+            });
+            if far.map_or(false, |far| near > far) {
+                return None;
+            }
+        }
+        Some(near)
+    }
+}
+
+enum Node<S>
+where
+    S: EuclideanSpace,
+{
+    Leaf {
+        bounds: Bounds<S>,
+        start: usize,
+        end: usize,
+    },
+    Branch {
+        bounds: Bounds<S>,
+        left: usize,
+        right: usize,
+    },
+}
+
+impl<S> Node<S>
+where
+    S: EuclideanSpace,
+{
+    fn bounds(&self) -> &Bounds<S> {
+        match self {
+            Node::Leaf { ref bounds, .. } | Node::Branch { ref bounds, .. } => bounds,
+        }
+    }
+}
+
+/// Bounding volume hierarchy over triangles.
+///
+/// # Examples
+///
+/// Casting a ray against a triangle:
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::{Point3, Vector3};
+/// use plexus::geometry::bvh::Bvh;
+/// use plexus::geometry::{Ray, Unit};
+///
+/// let bvh = Bvh::from_triangles(vec![(
+///     0usize,
+///     [
+///         Point3::new(0.0, 0.0, 0.0),
+///         Point3::new(1.0, 0.0, 0.0),
+///         Point3::new(0.0, 1.0, 0.0),
+///     ],
+/// )]);
+/// let ray = Ray {
+///     origin: Point3::new(0.25, 0.25, 1.0),
+///     direction: Unit::try_from_inner(-Vector3::z()).unwrap(),
+/// };
+/// let hit = bvh.cast_ray(&ray).unwrap();
+/// assert_eq!(0, hit.data);
+/// assert_eq!(1.0, hit.distance);
+/// ```
+pub struct Bvh<T, S>
+where
+    S: EuclideanSpace,
+{
+    triangles: Vec<(T, [S; 3])>,
+    nodes: Vec<Node<S>>,
+}
+
+impl<T, S> Bvh<T, S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    /// Builds a hierarchy from triangles and their associated data.
+    pub fn from_triangles<I>(triangles: I) -> Self
+    where
+        I: IntoIterator<Item = (T, [S; 3])>,
+    {
+        let mut triangles = triangles.into_iter().collect::<Vec<_>>();
+        let mut nodes = Vec::with_capacity((2 * triangles.len()) / LEAF_SIZE + 1);
+        if !triangles.is_empty() {
+            build(&mut nodes, &mut triangles, 0);
+        }
+        Bvh { triangles, nodes }
+    }
+
+    /// Gets the number of triangles in the hierarchy.
+    pub fn len(&self) -> usize {
+        self.triangles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.triangles.is_empty()
+    }
+
+    /// Gets an iterator over the triangles in the hierarchy and their
+    /// associated data.
+    pub fn triangles(&self) -> impl ExactSizeIterator<Item = (&T, &[S; 3])> {
+        self.triangles.iter().map(|(data, triangle)| (data, triangle))
+    }
+
+    /// Gets the triangles in the hierarchy with axis-aligned bounding boxes
+    /// that overlap the bounding box of the given triangle.
+    ///
+    /// This is a conservative query that can be used to cull triangles before
+    /// more expensive tests, such as intersection tests between triangles.
+    pub fn overlapping(&self, triangle: &[S; 3]) -> Vec<(&T, &[S; 3])> {
+        let bounds = Bounds::from_triangle(triangle);
+        let mut overlapping = Vec::new();
+        let mut stack = Vec::with_capacity(32);
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if !node.bounds().is_overlapping(&bounds) {
+                continue;
+            }
+            match *node {
+                Node::Leaf { start, end, .. } => {
+                    overlapping.extend(
+                        self.triangles[start..end]
+                            .iter()
+                            .filter(|(_, triangle)| {
+                                Bounds::from_triangle(triangle).is_overlapping(&bounds)
+                            })
+                            .map(|(data, triangle)| (data, triangle)),
+                    );
+                }
+                Node::Branch { left, right, .. } => {
+                    stack.push(left);
+                    stack.push(right);
+                }
+            }
+        }
+        overlapping
+    }
+
+    /// Gets the nearest intersection of a ray with the triangles in the
+    /// hierarchy.
+    ///
+    /// Intersections are found regardless of the winding of triangles.
+    /// Returns `None` if the ray does not intersect any triangle.
+    pub fn cast_ray(&self, ray: &Ray<S>) -> Option<RayHit<T, S>>
+    where
+        T: Copy,
+        Vector<S>: Cross<Output = Vector<S>>,
+    {
+        let origin = xyz(ray.origin);
+        let direction = xyz_vector::<S>(*ray.direction.get());
+        let mut nearest: Option<RayHit<T, S>> = None;
+        let mut stack = Vec::with_capacity(32);
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            match node.bounds().intersect_ray(&origin, &direction) {
+                Some(distance) => {
+                    if nearest
+                        .as_ref()
+                        .map_or(false, |nearest| distance > nearest.distance)
+                    {
+                        continue;
+                    }
+                }
+                _ => continue,
+            }
+            match *node {
+                Node::Leaf { start, end, .. } => {
+                    for (data, triangle) in self.triangles[start..end].iter() {
+                        if let Some((distance, barycentric)) = intersect_ray_triangle(ray, triangle)
+                        {
+                            if nearest
+                                .as_ref()
+                                .map_or(true, |nearest| distance < nearest.distance)
+                            {
+                                nearest = Some(RayHit {
+                                    data: *data,
+                                    distance,
+                                    barycentric,
+                                });
+                            }
+                        }
+                    }
+                }
+                Node::Branch { left, right, .. } => {
+                    stack.push(left);
+                    stack.push(right);
+                }
+            }
+        }
+        nearest
+    }
+
+    /// Gets the closest point to a query point on the triangles in the
+    /// hierarchy.
+    ///
+    /// Returns `None` if the hierarchy is empty.
+    pub fn closest_point(&self, point: S) -> Option<ClosestPoint<T, S>>
+    where
+        T: Copy,
+    {
+        let query = xyz(point);
+        let mut nearest: Option<(Scalar<S>, ClosestPoint<T, S>)> = None;
+        let mut stack = Vec::with_capacity(32);
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            let distance = node.bounds().squared_distance(&query);
+            if nearest
+                .as_ref()
+                .map_or(false, |(nearest, _)| distance >= *nearest)
+            {
+                continue;
+            }
+            match *node {
+                Node::Leaf { start, end, .. } => {
+                    for (data, triangle) in self.triangles[start..end].iter() {
+                        let (closest, barycentric) = closest_point_triangle(point, triangle);
+                        let offset = closest - point;
+                        let distance = offset.dot(offset);
+                        if nearest
+                            .as_ref()
+                            .map_or(true, |(nearest, _)| distance < *nearest)
+                        {
+                            nearest = Some((
+                                distance,
+                                ClosestPoint {
+                                    data: *data,
+                                    point: closest,
+                                    distance: offset.magnitude(),
+                                    barycentric,
+                                },
+                            ));
+                        }
+                    }
+                }
+                Node::Branch { left, right, .. } => {
+                    // Push the farther child first so that the nearer child is
+                    // visited first and more of the hierarchy can be culled.
+                    let l = self.nodes[left].bounds().squared_distance(&query);
+                    let r = self.nodes[right].bounds().squared_distance(&query);
+                    if l < r {
+                        stack.push(right);
+                        stack.push(left);
+                    }
+                    else {
+                        stack.push(left);
+                        stack.push(right);
+                    }
+                }
+            }
+        }
+        nearest.map(|(_, nearest)| nearest)
+    }
+}
+
+fn xyz<S>(point: S) -> [Scalar<S>; 3]
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let (x, y, z) = point.into_xyz();
+    [x, y, z]
+}
+
+fn xyz_vector<S>(vector: Vector<S>) -> [Scalar<S>; 3]
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let (x, y, z) = vector.into_xyz();
+    [x, y, z]
+}
+
+fn centroid<S>(triangle: &[S; 3], axis: usize) -> Scalar<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    // The sum is proportional to the centroid, which is sufficient for
+    // ordering.
+    triangle
+        .iter()
+        .map(|point| xyz(*point)[axis])
+        .fold(Zero::zero(), |sum, x| sum + x)
+}
+
+// Builds the subtree over the given triangles and returns the index of its
+// root. The offset is the index of the first of the triangles in the
+// hierarchy.
+fn build<T, S>(nodes: &mut Vec<Node<S>>, triangles: &mut [(T, [S; 3])], offset: usize) -> usize
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let bounds = triangles
+        .iter()
+        .map(|(_, triangle)| Bounds::from_triangle(triangle))
+        .reduce(|bounds, other| bounds.union(&other))
+        .expect("empty subtree");
+    let index = nodes.len();
+    nodes.push(Node::Leaf {
+        bounds,
+        start: offset,
+        end: offset + triangles.len(),
+    });
+    if triangles.len() > LEAF_SIZE {
+        let centroids = triangles
+            .iter()
+            .map(|(_, triangle)| {
+                [
+                    centroid(triangle, 0),
+                    centroid(triangle, 1),
+                    centroid(triangle, 2),
+                ]
+            })
+            .fold(None, |bounds: Option<Bounds<S>>, centroid| {
+                Some(match bounds {
+                    Some(mut bounds) => {
+                        bounds.extend(&centroid);
+                        bounds
+                    }
+                    _ => Bounds {
+                        lower: centroid,
+                        upper: centroid,
+                    },
+                })
+            })
+            .expect("empty subtree");
+        let axis = centroids.longest_axis();
+        triangles.sort_by(|(_, a), (_, b)| {
+            centroid(a, axis)
+                .partial_cmp(&centroid(b, axis))
+                .unwrap_or(Ordering::Equal)
+        });
+        let middle = triangles.len() / 2;
+        let (head, tail) = triangles.split_at_mut(middle);
+        let left = build(nodes, head, offset);
+        let right = build(nodes, tail, offset + middle);
+        nodes[index] = Node::Branch {
+            bounds,
+            left,
+            right,
+        };
+    }
+    index
+}
+
+// Intersects a ray with a triangle using the Möller-Trumbore algorithm.
+// Returns the distance to the intersection and its barycentric coordinates.
+#[allow(clippy::type_complexity)]
+fn intersect_ray_triangle<S>(
+    ray: &Ray<S>,
+    triangle: &[S; 3],
+) -> Option<(Scalar<S>, (Scalar<S>, Scalar<S>, Scalar<S>))>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    let [a, b, c] = *triangle;
+    let direction = *ray.direction.get();
+    let ab = b - a;
+    let ac = c - a;
+    let p = direction.cross(ac);
+    let determinant = ab.dot(p);
+    if determinant.is_zero() {
+        // The ray is parallel to the triangle.
+        return None;
+    }
+    let inverse = Scalar::<S>::one() / determinant;
+    let s = ray.origin - a;
+    let u = s.dot(p) * inverse;
+    if u < Zero::zero() || u > One::one() {
+        return None;
+    }
+    let q = s.cross(ab);
+    let v = direction.dot(q) * inverse;
+    if v < Zero::zero() || u + v > One::one() {
+        return None;
+    }
+    let distance = ac.dot(q) * inverse;
+    if distance < Zero::zero() {
+        return None;
+    }
+    Some((distance, (Scalar::<S>::one() - u - v, u, v)))
+}
+
+// Gets the closest point on a triangle to a query point and its barycentric
+// coordinates. This is the algorithm described by Ericson in _Real-Time
+// Collision Detection_, which determines the Voronoi region of the triangle
+// that contains the query point.
+#[allow(clippy::many_single_char_names)]
+fn closest_point_triangle<S>(point: S, triangle: &[S; 3]) -> (S, (Scalar<S>, Scalar<S>, Scalar<S>))
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let zero = Scalar::<S>::zero();
+    let one = Scalar::<S>::one();
+    let [a, b, c] = *triangle;
+    let ab = b - a;
+    let ac = c - a;
+    let ap = point - a;
+    let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+    if d1 <= zero && d2 <= zero {
+        return (a, (one, zero, zero));
+    }
+    let bp = point - b;
+    let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+    if d3 >= zero && d4 <= d3 {
+        return (b, (zero, one, zero));
+    }
+    let vc = (d1 * d4) - (d3 * d2);
+    if vc <= zero && d1 >= zero && d3 <= zero {
+        let v = d1 / (d1 - d3);
+        return (a + (ab * v), (one - v, v, zero));
+    }
+    let cp = point - c;
+    let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+    if d6 >= zero && d5 <= d6 {
+        return (c, (zero, zero, one));
+    }
+    let vb = (d5 * d2) - (d1 * d6);
+    if vb <= zero && d2 >= zero && d6 <= zero {
+        let w = d2 / (d2 - d6);
+        return (a + (ac * w), (one - w, zero, w));
+    }
+    let va = (d3 * d6) - (d5 * d4);
+    if va <= zero && (d4 - d3) >= zero && (d5 - d6) >= zero {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return (b + ((c - b) * w), (zero, one - w, w));
+    }
+    let denominator = va + vb + vc;
+    if denominator.is_zero() {
+        // The triangle is degenerate.
+        return (a, (one, zero, zero));
+    }
+    let v = vb / denominator;
+    let w = vc / denominator;
+    (a + (ab * v) + (ac * w), (one - v - w, v, w))
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Point3, Vector3};
+    use theon::query::{Ray, Unit};
+
+    use crate::geometry::bvh::Bvh;
+
+    type E3 = Point3<f64>;
+
+    // Gets a grid of unit squares in the XY plane, each split into two
+    // triangles.
+    fn grid(n: usize) -> Vec<((usize, usize, usize), [E3; 3])> {
+        let mut triangles = Vec::new();
+        for i in 0..n {
+            for j in 0..n {
+                let (x, y) = (i as f64, j as f64);
+                let a = E3::new(x, y, 0.0);
+                let b = E3::new(x + 1.0, y, 0.0);
+                let c = E3::new(x + 1.0, y + 1.0, 0.0);
+                let d = E3::new(x, y + 1.0, 0.0);
+                triangles.push(((i, j, 0), [a, b, c]));
+                triangles.push(((i, j, 1), [a, c, d]));
+            }
+        }
+        triangles
+    }
+
+    #[test]
+    fn cast_ray_grid() {
+        let bvh = Bvh::from_triangles(grid(8));
+        assert_eq!(128, bvh.len());
+
+        let ray = Ray {
+            origin: E3::new(5.75, 2.25, 2.0),
+            direction: Unit::try_from_inner(-Vector3::z()).unwrap(),
+        };
+        let hit = bvh.cast_ray(&ray).unwrap();
+        assert_eq!((5, 2, 0), hit.data);
+        assert!((hit.distance - 2.0).abs() < 1e-9);
+        let (u, v, w) = hit.barycentric;
+        assert!((u - 0.25).abs() < 1e-9);
+        assert!((v - 0.5).abs() < 1e-9);
+        assert!((w - 0.25).abs() < 1e-9);
+
+        // Rays that point away from or miss the grid do not intersect it.
+        let ray = Ray {
+            origin: E3::new(5.75, 2.25, 2.0),
+            direction: Unit::try_from_inner(Vector3::z()).unwrap(),
+        };
+        assert!(bvh.cast_ray(&ray).is_none());
+        let ray = Ray {
+            origin: E3::new(10.0, 2.25, 2.0),
+            direction: Unit::try_from_inner(-Vector3::z()).unwrap(),
+        };
+        assert!(bvh.cast_ray(&ray).is_none());
+    }
+
+    #[test]
+    fn closest_point_grid() {
+        let bvh = Bvh::from_triangles(grid(8));
+
+        // The closest point is within a triangle.
+        let closest = bvh.closest_point(E3::new(5.75, 2.25, 3.0)).unwrap();
+        assert_eq!((5, 2, 0), closest.data);
+        assert!((closest.point - E3::new(5.75, 2.25, 0.0)).norm() < 1e-9);
+        assert!((closest.distance - 3.0).abs() < 1e-9);
+
+        // The closest point is on the boundary of the grid.
+        let closest = bvh.closest_point(E3::new(10.0, 2.5, 0.0)).unwrap();
+        assert!((closest.point - E3::new(8.0, 2.5, 0.0)).norm() < 1e-9);
+        assert!((closest.distance - 2.0).abs() < 1e-9);
+        assert_eq!((7, 2, 0), closest.data);
+        let (u, v, w) = closest.barycentric;
+        assert_eq!(0.0, u);
+        assert!((v - 0.5).abs() < 1e-9);
+        assert!((w - 0.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,311 @@
+//! Spatial indexing of points.
+//!
+//! This module provides a $k$-d tree over points in Euclidean spaces of any
+//! dimension. Each point is associated with arbitrary data, such as the key of
+//! the vertex from which it is derived, which is returned by queries. Trees
+//! support $k$-nearest neighbor and radius queries.
+//!
+//! See [`MeshGraph::kdtree`] and [`MeshBuffer::kdtree_with`].
+//!
+//! [`MeshBuffer::kdtree_with`]: crate::buffer::MeshBuffer::kdtree_with
+//! [`MeshGraph::kdtree`]: crate::graph::MeshGraph::kdtree
+
+use num::Zero;
+use smallvec::SmallVec;
+use std::cmp::Ordering;
+use theon::adjunct::{Adjunct, IntoItems};
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
+
+/// Point in a [`KdTree`] found by a query.
+#[derive(Clone, Copy)]
+pub struct Neighbor<T, S>
+where
+    S: EuclideanSpace,
+{
+    /// Data associated with the point.
+    pub data: T,
+    pub point: S,
+    /// Distance from the query point to the point.
+    pub distance: Scalar<S>,
+}
+
+struct Entry<T, S>
+where
+    S: EuclideanSpace,
+{
+    data: T,
+    point: S,
+    coordinates: SmallVec<[Scalar<S>; 4]>,
+}
+
+/// $k$-d tree over points.
+///
+/// The tree is balanced and immutable. Points are split at the median of each
+/// axis in turn, so the tree is stored implicitly by the order of its points.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point2;
+/// use plexus::geometry::kdtree::KdTree;
+///
+/// let tree = KdTree::from_points(vec![
+///     ('a', Point2::new(0.0, 0.0)),
+///     ('b', Point2::new(1.0, 0.0)),
+///     ('c', Point2::new(0.0, 2.0)),
+/// ]);
+/// let neighbors = tree.nearest(Point2::new(0.9, 0.1), 2);
+/// assert_eq!('b', neighbors[0].data);
+/// assert_eq!('a', neighbors[1].data);
+/// ```
+pub struct KdTree<T, S>
+where
+    S: EuclideanSpace,
+{
+    entries: Vec<Entry<T, S>>,
+}
+
+impl<T, S> KdTree<T, S>
+where
+    S: EuclideanSpace,
+    Vector<S>: Adjunct<Item = Scalar<S>> + IntoItems,
+{
+    /// Builds a tree from points and their associated data.
+    pub fn from_points<I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = (T, S)>,
+    {
+        let mut entries = points
+            .into_iter()
+            .map(|(data, point)| Entry {
+                data,
+                point,
+                coordinates: coordinates(point),
+            })
+            .collect::<Vec<_>>();
+        build(&mut entries, 0);
+        KdTree { entries }
+    }
+
+    /// Gets the number of points in the tree.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Gets an iterator over the points in the tree and their associated data.
+    pub fn points(&self) -> impl ExactSizeIterator<Item = (&T, &S)> {
+        self.entries.iter().map(|entry| (&entry.data, &entry.point))
+    }
+
+    /// Gets the `k` nearest points to a query point.
+    ///
+    /// Points are ordered by their distance from the query point, nearest
+    /// first. Fewer than `k` points are returned if the tree contains fewer
+    /// than `k` points.
+    pub fn nearest(&self, point: S, k: usize) -> Vec<Neighbor<T, S>>
+    where
+        T: Copy,
+    {
+        let query = coordinates(point);
+        let mut nearest = Vec::with_capacity(k + 1);
+        if k > 0 {
+            search_nearest(&self.entries, 0, &query, k, &mut nearest);
+        }
+        nearest
+            .into_iter()
+            .map(|(_, entry)| neighbor(entry, point))
+            .collect()
+    }
+
+    /// Gets the points within a distance of a query point.
+    ///
+    /// Points are ordered by their distance from the query point, nearest
+    /// first. Points at exactly the given distance are included.
+    pub fn within(&self, point: S, radius: Scalar<S>) -> Vec<Neighbor<T, S>>
+    where
+        T: Copy,
+    {
+        let query = coordinates(point);
+        let mut within = Vec::new();
+        search_within(&self.entries, 0, &query, radius * radius, &mut within);
+        within.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        within
+            .into_iter()
+            .map(|(_, entry)| neighbor(entry, point))
+            .collect()
+    }
+}
+
+fn neighbor<T, S>(entry: &Entry<T, S>, point: S) -> Neighbor<T, S>
+where
+    S: EuclideanSpace,
+    T: Copy,
+{
+    Neighbor {
+        data: entry.data,
+        point: entry.point,
+        distance: (entry.point - point).magnitude(),
+    }
+}
+
+// Visits the subtree of the given entries. The nearest entries are kept in
+// order with their squared distances.
+fn search_nearest<'a, T, S>(
+    entries: &'a [Entry<T, S>],
+    depth: usize,
+    query: &[Scalar<S>],
+    k: usize,
+    nearest: &mut Vec<(Scalar<S>, &'a Entry<T, S>)>,
+) where
+    S: EuclideanSpace,
+{
+    if entries.is_empty() {
+        return;
+    }
+    let median = entries.len() / 2;
+    let entry = &entries[median];
+    let distance = squared_distance(&entry.coordinates, query);
+    if nearest.len() < k || distance < nearest[nearest.len() - 1].0 {
+        let index = nearest
+            .iter()
+            .position(|(other, _)| distance < *other)
+            .unwrap_or(nearest.len());
+        nearest.insert(index, (distance, entry));
+        nearest.truncate(k);
+    }
+    let axis = depth % query.len();
+    let difference = query[axis] - entry.coordinates[axis];
+    let (near, far) = if difference < Zero::zero() {
+        (&entries[..median], &entries[median + 1..])
+    }
+    else {
+        (&entries[median + 1..], &entries[..median])
+    };
+    search_nearest(near, depth + 1, query, k, nearest);
+    if nearest.len() < k || (difference * difference) < nearest[nearest.len() - 1].0 {
+        search_nearest(far, depth + 1, query, k, nearest);
+    }
+}
+
+// Visits the subtree of the given entries. Entries within the squared radius
+// are collected with their squared distances.
+fn search_within<'a, T, S>(
+    entries: &'a [Entry<T, S>],
+    depth: usize,
+    query: &[Scalar<S>],
+    radius: Scalar<S>,
+    within: &mut Vec<(Scalar<S>, &'a Entry<T, S>)>,
+) where
+    S: EuclideanSpace,
+{
+    if entries.is_empty() {
+        return;
+    }
+    let median = entries.len() / 2;
+    let entry = &entries[median];
+    let distance = squared_distance(&entry.coordinates, query);
+    if distance <= radius {
+        within.push((distance, entry));
+    }
+    let axis = depth % query.len();
+    let difference = query[axis] - entry.coordinates[axis];
+    if difference <= Zero::zero() || (difference * difference) <= radius {
+        search_within(&entries[..median], depth + 1, query, radius, within);
+    }
+    if difference >= Zero::zero() || (difference * difference) <= radius {
+        search_within(&entries[median + 1..], depth + 1, query, radius, within);
+    }
+}
+
+fn coordinates<S>(point: S) -> SmallVec<[Scalar<S>; 4]>
+where
+    S: EuclideanSpace,
+    Vector<S>: Adjunct<Item = Scalar<S>> + IntoItems,
+{
+    (point - S::origin()).into_items().into_iter().collect()
+}
+
+fn squared_distance<T>(a: &[T], b: &[T]) -> T
+where
+    T: Copy + Zero + std::ops::Mul<Output = T> + std::ops::Sub<Output = T>,
+{
+    a.iter()
+        .zip(b.iter())
+        .map(|(a, b)| *a - *b)
+        .fold(T::zero(), |sum, d| sum + (d * d))
+}
+
+// Orders the given entries such that the median along the axis for the given
+// depth partitions the entries into subtrees.
+fn build<T, S>(entries: &mut [Entry<T, S>], depth: usize)
+where
+    S: EuclideanSpace,
+{
+    if entries.len() <= 1 {
+        return;
+    }
+    let axis = depth % entries[0].coordinates.len();
+    let median = entries.len() / 2;
+    entries.select_nth_unstable_by(median, |a, b| {
+        a.coordinates[axis]
+            .partial_cmp(&b.coordinates[axis])
+            .unwrap_or(Ordering::Equal)
+    });
+    let (head, tail) = entries.split_at_mut(median);
+    build(head, depth + 1);
+    build(&mut tail[1..], depth + 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Point2, Point3};
+
+    use crate::geometry::kdtree::KdTree;
+
+    #[test]
+    fn nearest_and_within_grid() {
+        let mut points = Vec::new();
+        for i in 0..10 {
+            for j in 0..10 {
+                points.push(((i, j), Point2::new(i as f64, j as f64)));
+            }
+        }
+        let tree = KdTree::from_points(points);
+        assert_eq!(100, tree.len());
+
+        let neighbors = tree.nearest(Point2::new(3.1, 6.8), 3);
+        assert_eq!(3, neighbors.len());
+        assert_eq!((3, 7), neighbors[0].data);
+        assert_eq!((3, 6), neighbors[1].data);
+        assert_eq!((4, 7), neighbors[2].data);
+        assert!(neighbors[0].distance <= neighbors[1].distance);
+
+        // The four neighbors of a point and the point itself.
+        let within = tree.within(Point2::new(5.0, 5.0), 1.0);
+        assert_eq!(5, within.len());
+        assert_eq!((5, 5), within[0].data);
+        assert!(within.iter().all(|neighbor| neighbor.distance <= 1.0));
+
+        // Queries are not limited by the extent of the tree.
+        let neighbors = tree.nearest(Point2::new(-5.0, -5.0), 200);
+        assert_eq!(100, neighbors.len());
+        assert_eq!((0, 0), neighbors[0].data);
+    }
+
+    #[test]
+    fn nearest_three_dimensional() {
+        let tree = KdTree::from_points((0..64).map(|n| {
+            let (x, y, z) = (n % 4, (n / 4) % 4, n / 16);
+            (n, Point3::new(x as f64, y as f64, z as f64))
+        }));
+        let neighbors = tree.nearest(Point3::new(2.2, 1.1, 2.9), 1);
+        assert_eq!(2 + 4 + (3 * 16), neighbors[0].data);
+    }
+}
@@ -11,7 +11,10 @@
 
 use num::{One, Zero};
 
+pub mod bvh;
+pub mod kdtree;
 pub mod partition;
+pub mod tangent;
 
 pub use theon::query::*;
 pub use theon::space::{Scalar, Vector};
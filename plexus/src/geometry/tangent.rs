@@ -0,0 +1,347 @@
+//! Tangent frames for normal mapping.
+//!
+//! This module computes per-corner tangent frames for triangles using the
+//! conventions of [MikkTSpace][mikktspace], which is the de facto standard for
+//! baking and rendering tangent space normal maps. Like MikkTSpace, corners
+//! that share a position, normal, and texture coordinate are welded and their
+//! tangents are accumulated using angle weights after projection into the
+//! tangent plane of the normal. Corners of triangles with mirrored texture
+//! coordinates are never welded with corners of triangles that are not
+//! mirrored, and the handedness of the frame is encoded in a sign. This is not
+//! a bit-exact port of the reference implementation, but produces matching
+//! frames for well-formed inputs.
+//!
+//! See [`MeshGraph::to_mesh_by_face_with_tangents`] and
+//! [`MeshBuffer::tangents_with`].
+//!
+//! [mikktspace]: http://www.mikktspace.com
+//!
+//! [`MeshBuffer::tangents_with`]: crate::buffer::MeshBuffer::tangents_with
+//! [`MeshGraph::to_mesh_by_face_with_tangents`]: crate::graph::MeshGraph::to_mesh_by_face_with_tangents
+
+use decorum::Real;
+use num::{One, ToPrimitive, Zero};
+use std::collections::HashMap;
+use theon::ops::{Cross, Dot};
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use typenum::U3;
+
+/// Geometry of a corner of a triangle.
+///
+/// Corners are read from mesh data structures via accessor functions.
+#[derive(Clone, Copy)]
+pub struct Corner<S>
+where
+    S: EuclideanSpace,
+{
+    pub position: S,
+    pub normal: Vector<S>,
+    /// Texture coordinates $(u,v)$.
+    pub uv: (Scalar<S>, Scalar<S>),
+}
+
+/// Tangent frame of a corner of a triangle.
+///
+/// Together with the normal of the corner, the tangent and bitangent form an
+/// orthonormal basis. The tangent points in the direction of increasing $u$
+/// and the bitangent points in the direction of increasing $v$.
+#[derive(Clone, Copy)]
+pub struct Tangent<S>
+where
+    S: EuclideanSpace,
+{
+    pub tangent: Vector<S>,
+    /// The bitangent, which is the cross product of the normal and tangent
+    /// multiplied by the sign.
+    pub bitangent: Vector<S>,
+    /// Handedness of the frame, either $1$ or $-1$.
+    ///
+    /// This is the `w` component of tangents in MikkTSpace. Shaders can
+    /// reconstruct the bitangent from the normal, tangent, and sign.
+    pub sign: Scalar<S>,
+}
+
+impl<S> Tangent<S>
+where
+    S: EuclideanSpace,
+{
+    fn zero() -> Self {
+        Tangent {
+            tangent: Zero::zero(),
+            bitangent: Zero::zero(),
+            sign: One::one(),
+        }
+    }
+}
+
+// Corners are welded by value.
+type CornerKey = ([u64; 8], bool);
+
+fn bits<T>(value: T) -> u64
+where
+    T: ToPrimitive,
+{
+    value.to_f64().unwrap_or(f64::NAN).to_bits()
+}
+
+fn corner_key<S>(corner: &Corner<S>, orientation: bool) -> CornerKey
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let (x, y, z) = corner.position.into_xyz();
+    let (nx, ny, nz) = corner.normal.into_xyz();
+    let (u, v) = corner.uv;
+    (
+        [
+            bits(x),
+            bits(y),
+            bits(z),
+            bits(nx),
+            bits(ny),
+            bits(nz),
+            bits(u),
+            bits(v),
+        ],
+        orientation,
+    )
+}
+
+// Projects a vector into the plane orthogonal to a unit normal.
+fn reject<S>(vector: Vector<S>, normal: Vector<S>) -> Vector<S>
+where
+    S: EuclideanSpace,
+{
+    vector - (normal * normal.dot(vector))
+}
+
+/// Computes the tangent frames of the corners of triangles.
+///
+/// Returns the frames of each corner of each triangle in the same order as the
+/// input. If a frame cannot be determined from texture coordinates, then an
+/// arbitrary frame that is orthogonal to the normal is used. Corners with zero
+/// normals are given zero tangent frames.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::{Point3, Vector3};
+/// use plexus::geometry::tangent::{self, Corner};
+///
+/// let corner = |x: f64, y: f64| Corner {
+///     position: Point3::new(x, y, 0.0),
+///     normal: Vector3::z(),
+///     uv: (x, y),
+/// };
+/// let frames = tangent::tangents(vec![[corner(0.0, 0.0), corner(1.0, 0.0), corner(0.0, 1.0)]]);
+/// assert_eq!(Vector3::x(), frames[0][0].tangent);
+/// assert_eq!(Vector3::y(), frames[0][0].bitangent);
+/// ```
+pub fn tangents<S, I>(triangles: I) -> Vec<[Tangent<S>; 3]>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+    I: IntoIterator<Item = [Corner<S>; 3]>,
+{
+    let triangles = triangles.into_iter().collect::<Vec<_>>();
+    let mut keys = Vec::with_capacity(triangles.len());
+    let mut sums = HashMap::<CornerKey, Vector<S>>::new();
+    for triangle in triangles.iter() {
+        let [a, b, c] = triangle;
+        let e1 = b.position - a.position;
+        let e2 = c.position - a.position;
+        let (du1, dv1) = (b.uv.0 - a.uv.0, b.uv.1 - a.uv.1);
+        let (du2, dv2) = (c.uv.0 - a.uv.0, c.uv.1 - a.uv.1);
+        // Twice the signed area of the triangle in texture space. A negative
+        // area indicates mirrored texture coordinates.
+        let area = (du1 * dv2) - (du2 * dv1);
+        let orientation = area >= Zero::zero();
+        let sign = if orientation {
+            Scalar::<S>::one()
+        }
+        else {
+            -Scalar::<S>::one()
+        };
+        // Derivative of position with respect to `u` scaled by the area in
+        // texture space. Only its direction is used. Bitangents are derived
+        // from tangents and the orientation of texture space.
+        let du = ((e1 * dv2) - (e2 * dv1)) * sign;
+        let mut triangle_keys = [corner_key(a, orientation); 3];
+        for (n, corner) in triangle.iter().enumerate() {
+            let key = corner_key(corner, orientation);
+            triangle_keys[n] = key;
+            if area.is_zero() {
+                continue;
+            }
+            let normal = match corner.normal.normalize() {
+                Some(normal) => normal,
+                _ => continue,
+            };
+            let p = corner.position;
+            let ab = triangle[(n + 1) % 3].position - p;
+            let ac = triangle[(n + 2) % 3].position - p;
+            let angle = Real::atan2(ab.cross(ac).magnitude(), ab.dot(ac));
+            if let Some(du) = reject::<S>(du, normal).normalize() {
+                let sum = sums.entry(key).or_insert_with(Zero::zero);
+                *sum = *sum + (du * angle);
+            }
+        }
+        keys.push(triangle_keys);
+    }
+    triangles
+        .iter()
+        .zip(keys)
+        .map(|(triangle, keys)| {
+            let mut frames = [Tangent::zero(); 3];
+            for (n, (corner, key)) in triangle.iter().zip(keys.iter()).enumerate() {
+                let normal = match corner.normal.normalize() {
+                    Some(normal) => normal,
+                    _ => continue,
+                };
+                let du = sums.get(key).copied().unwrap_or_else(Zero::zero);
+                // Fall back to an edge of the triangle if the tangent cannot
+                // be determined from texture coordinates.
+                let edge = triangle[(n + 1) % 3].position - corner.position;
+                let tangent = match reject::<S>(du, normal)
+                    .normalize()
+                    .or_else(|| reject::<S>(edge, normal).normalize())
+                {
+                    Some(tangent) => tangent,
+                    _ => continue,
+                };
+                let sign = if key.1 {
+                    Scalar::<S>::one()
+                }
+                else {
+                    -Scalar::<S>::one()
+                };
+                frames[n] = Tangent {
+                    tangent,
+                    bitangent: normal.cross(tangent) * sign,
+                    sign,
+                };
+            }
+            frames
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::{Point3, Vector3};
+
+    use crate::buffer::MeshBuffer3;
+    use crate::geometry::tangent::{self, Corner, Tangent};
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+
+    type E3 = Point3<f64>;
+
+    // Asserts that a frame is orthonormal with the given normal and that its
+    // handedness matches the given sign.
+    fn assert_frame(frame: &Tangent<E3>, normal: Vector3<f64>, sign: f64) {
+        let normal = normal.normalize();
+        assert_eq!(sign, frame.sign);
+        assert!((frame.tangent.norm() - 1.0).abs() < 1e-9);
+        assert!(frame.tangent.dot(&normal).abs() < 1e-9);
+        assert!(((normal.cross(&frame.tangent) * sign) - frame.bitangent).norm() < 1e-9);
+    }
+
+    fn corner(x: f64, y: f64, uv: (f64, f64)) -> Corner<E3> {
+        Corner {
+            position: Point3::new(x, y, 0.0),
+            normal: Vector3::z(),
+            uv,
+        }
+    }
+
+    #[test]
+    fn mirrored_sign() {
+        // Two triangles that share an edge with texture coordinates mirrored
+        // across that edge.
+        let frames = tangent::tangents(vec![
+            [
+                corner(0.0, 0.0, (0.0, 0.0)),
+                corner(1.0, 0.0, (1.0, 0.0)),
+                corner(0.0, 1.0, (0.0, 1.0)),
+            ],
+            [
+                corner(0.0, 0.0, (0.0, 0.0)),
+                corner(0.0, -1.0, (0.0, 1.0)),
+                corner(1.0, 0.0, (1.0, 0.0)),
+            ],
+        ]);
+
+        for frame in frames[0].iter() {
+            assert_eq!(1.0, frame.sign);
+            assert!((frame.tangent - Vector3::x()).norm() < 1e-9);
+            assert!((frame.bitangent - Vector3::y()).norm() < 1e-9);
+        }
+        for frame in frames[1].iter() {
+            assert_eq!(-1.0, frame.sign);
+            assert!((frame.tangent - Vector3::x()).norm() < 1e-9);
+            assert!((frame.bitangent + Vector3::y()).norm() < 1e-9);
+        }
+    }
+
+    // Texture coordinates of a sphere are projected onto the $xy$-plane, so
+    // the texture coordinates of triangles in the lower hemisphere are
+    // mirrored.
+    #[test]
+    fn sphere_buffer_frames() {
+        let sphere = UvSphere::new(16, 8);
+        let buffer = MeshBuffer3::<usize, E3>::from_raw_buffers(
+            sphere.indexing_polygons::<Position>().triangulate(),
+            sphere.vertices::<Position<E3>>(),
+        )
+        .unwrap();
+        let frames = buffer.tangents_with(|position| Corner {
+            position: *position,
+            normal: position.coords,
+            uv: (position.x, position.y),
+        });
+
+        let vertices = buffer.as_vertex_slice();
+        for (trigon, frames) in buffer.as_index_slice().iter().zip(frames) {
+            let [a, b, c] = trigon.into_array().map(|index| vertices[index]);
+            let sign = (b - a).cross(&(c - a)).z.signum();
+            for (index, frame) in trigon.into_array().into_iter().zip(frames.into_array()) {
+                assert_frame(&frame, vertices[index].coords, sign);
+            }
+        }
+    }
+
+    #[test]
+    fn sphere_graph_frames() {
+        let graph: MeshGraph<E3> = UvSphere::new(16, 8)
+            .polygons::<Position<Point3<R64>>>()
+            .triangulate()
+            .collect();
+        let buffer: MeshBuffer3<usize, (Vector3<f64>, f64, Tangent<E3>)> = graph
+            .to_mesh_by_face_with_tangents(
+                |_, vertex| {
+                    let position = *vertex.position();
+                    Corner {
+                        position,
+                        normal: vertex.normal().unwrap(),
+                        uv: (position.x, position.y),
+                    }
+                },
+                |face, vertex, frame| {
+                    let sign = face.normal().unwrap().z.signum();
+                    (vertex.normal().unwrap(), sign, frame)
+                },
+            )
+            .unwrap();
+
+        assert_eq!(graph.face_count() * 3, buffer.as_vertex_slice().len());
+        for (normal, sign, frame) in buffer.as_vertex_slice() {
+            assert_frame(frame, *normal, *sign);
+        }
+    }
+}
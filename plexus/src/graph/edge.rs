@@ -3,7 +3,8 @@ use derivative::Derivative;
 use std::borrow::Borrow;
 use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
-use theon::space::{EuclideanSpace, Scalar, Vector};
+use theon::ops::Cross;
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
 use theon::{AsPosition, AsPositionMut};
 
 use crate::entity::borrow::{Reborrow, ReborrowInto, ReborrowMut};
@@ -12,7 +13,7 @@ use crate::entity::view::{Bind, ClosedView, Orphan, Rebind, Unbind, View};
 use crate::entity::{Entity, Payload};
 use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::face::{Face, FaceKey, FaceOrphan, FaceView, Ring};
-use crate::graph::geometry::{ArcNormal, EdgeMidpoint, VertexPosition};
+use crate::graph::geometry::{self, ArcNormal, EdgeMidpoint, FaceNormal, VertexPosition};
 use crate::graph::mutation::edge::{
     self, ArcBridgeCache, ArcExtrudeCache, EdgeRemoveCache, EdgeSplitCache,
 };
@@ -1266,6 +1267,25 @@ where
     }
 }
 
+/// Classification of feature edges.
+///
+/// Graphs are manifold at edges, so every edge is adjacent to one or two
+/// faces and non-manifold edges cannot occur. Non-manifold edges can instead be
+/// found in raw buffers before constructing a graph using
+/// [`index::non_manifold_edges`].
+///
+/// See [`EdgeView::feature`] and [`MeshGraph::feature_edges`].
+///
+/// [`index::non_manifold_edges`]: crate::index::non_manifold_edges
+/// [`MeshGraph::feature_edges`]: crate::graph::MeshGraph::feature_edges
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EdgeFeature {
+    /// The edge is adjacent to only one face.
+    Boundary,
+    /// The dihedral angle of the edge exceeds a threshold.
+    Sharp,
+}
+
 /// Edge key.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub struct EdgeKey(u64);
@@ -1397,6 +1417,81 @@ where
     {
         G::midpoint(self.to_ref()).expect_consistent()
     }
+
+    /// Gets the distance between the vertices of the edge.
+    pub fn length(&self) -> Scalar<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let arc = self.arc();
+        (*arc.destination_vertex().position() - *arc.source_vertex().position()).magnitude()
+    }
+}
+
+impl<B, M, G> EdgeView<B>
+where
+    B: Reborrow<Target = M>,
+    M: AsStorage<Arc<G>>
+        + AsStorage<Edge<G>>
+        + AsStorage<Face<G>>
+        + AsStorage<Vertex<G>>
+        + Consistent
+        + Parametric<Data = G>,
+    G: GraphData,
+{
+    /// Gets the dihedral angle of the edge.
+    ///
+    /// The dihedral angle is the unsigned angle between the normals of the
+    /// faces adjacent to the edge in the interval $[0,\pi]$. The angle is zero
+    /// if the faces are coplanar.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the edge is a boundary edge or if the normal of an
+    /// adjacent face cannot be computed.
+    pub fn dihedral_angle(&self) -> Result<Scalar<VertexPosition<G>>, GraphError>
+    where
+        G: FaceNormal,
+        G::Vertex: AsPosition,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let arc = self.arc();
+        let a = arc.face().ok_or(GraphError::TopologyNotFound)?.normal()?;
+        let b = arc
+            .opposite_arc()
+            .face()
+            .ok_or(GraphError::TopologyNotFound)?
+            .normal()?;
+        Ok(geometry::angle::<VertexPosition<G>>(a, b))
+    }
+
+    /// Classifies the edge as a feature given a threshold dihedral angle.
+    ///
+    /// Returns `None` if the edge is not a feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dihedral angle of the edge cannot be computed.
+    pub fn feature<T>(&self, angle: T) -> Result<Option<EdgeFeature>, GraphError>
+    where
+        G: FaceNormal,
+        G::Vertex: AsPosition,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+        VertexPosition<G>: EuclideanSpace,
+        T: Into<Scalar<VertexPosition<G>>>,
+    {
+        if self.is_boundary_edge() {
+            Ok(Some(EdgeFeature::Boundary))
+        }
+        else if self.dihedral_angle()? > angle.into() {
+            Ok(Some(EdgeFeature::Sharp))
+        }
+        else {
+            Ok(None)
+        }
+    }
 }
 
 impl<B> Borrow<EdgeKey> for EdgeView<B>
@@ -1864,9 +1959,10 @@ where
 mod tests {
     use decorum::R64;
     use nalgebra::{Point2, Point3};
+    use std::f64::consts::FRAC_PI_2;
 
     use crate::geometry::FromGeometry;
-    use crate::graph::{ArcKey, GraphData, MeshGraph};
+    use crate::graph::{ArcKey, EdgeFeature, GraphData, MeshGraph};
     use crate::index::HashIndexer;
     use crate::prelude::*;
     use crate::primitive::cube::Cube;
@@ -1942,6 +2038,30 @@ mod tests {
         assert_eq!(3, graph.face_count());
     }
 
+    #[test]
+    fn feature_edges() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new()
+            .polygons::<Position<E3>>() // 6 quadrilaterals, 24 vertices.
+            .collect();
+        for edge in graph.edges() {
+            assert!((edge.length() - 1.0).abs() < 1e-9);
+            assert!((edge.dihedral_angle().unwrap() - FRAC_PI_2).abs() < 1e-9);
+            assert_eq!(Some(EdgeFeature::Sharp), edge.feature(1.0).unwrap());
+            assert_eq!(None, edge.feature(2.0).unwrap());
+        }
+
+        // Removing a face exposes four boundary edges.
+        let key = graph.faces().next().unwrap().key();
+        graph.face_mut(key).unwrap().remove();
+        assert_eq!(4, graph.feature_edges(2.0).unwrap().len());
+        assert_eq!(12, graph.feature_edges(1.0).unwrap().len());
+        for key in graph.feature_edges(2.0).unwrap() {
+            let edge = graph.edge(key).unwrap();
+            assert!(edge.dihedral_angle().is_err());
+            assert_eq!(Some(EdgeFeature::Boundary), edge.feature(2.0).unwrap());
+        }
+    }
+
     #[test]
     fn split_edge() {
         let (indices, vertices) = Cube::new()
@@ -20,7 +20,7 @@ use crate::entity::view::{Bind, ClosedView, Orphan, Rebind, Unbind, View};
 use crate::entity::{Entity, Payload};
 use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::edge::{Arc, ArcKey, ArcOrphan, ArcView, Edge};
-use crate::graph::geometry::{FaceCentroid, FaceNormal, FacePlane, VertexPosition};
+use crate::graph::geometry::{FaceArea, FaceCentroid, FaceNormal, FacePlane, VertexPosition};
 use crate::graph::mutation::face::{
     self, FaceBridgeCache, FaceExtrudeCache, FaceInsertCache, FacePokeCache, FaceRemoveCache,
     FaceSplitCache,
@@ -277,6 +277,14 @@ where
         G::centroid(self.to_ref()).expect_consistent()
     }
 
+    pub fn area(&self) -> Scalar<VertexPosition<G>>
+    where
+        G: FaceArea,
+        G::Vertex: AsPosition,
+    {
+        G::area(self.to_ref()).expect_consistent()
+    }
+
     pub fn normal(&self) -> Result<Vector<VertexPosition<G>>, GraphError>
     where
         G: FaceNormal,
@@ -7,9 +7,12 @@
 // necessary, constraints are specified there so that they do not pollute user
 // code.
 
-use theon::ops::{Cross, Interpolate, Project};
+use decorum::Real;
+use num::{One, Zero};
+use smallvec::SmallVec;
+use theon::ops::{Cross, Dot, Interpolate, Project};
 use theon::query::Plane;
-use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Vector, VectorSpace};
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector, VectorSpace};
 use theon::{AsPosition, Position};
 use typenum::U3;
 
@@ -25,6 +28,23 @@ use crate::IteratorExt as _;
 
 pub type VertexPosition<G> = Position<<G as GraphData>::Vertex>;
 
+/// Weighting of face normals used to compute vertex normals.
+///
+/// See [`VertexNormal`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum NormalWeight {
+    /// Each adjacent face contributes equally.
+    Uniform,
+    /// Each adjacent face contributes in proportion to its area.
+    Area,
+    /// Each adjacent face contributes in proportion to the interior angle of
+    /// the corner that it forms at the vertex.
+    ///
+    /// Angle weighting is insensitive to how faces are tessellated and is
+    /// typically the best choice for shading.
+    Angle,
+}
+
 pub trait VertexCentroid: GraphData
 where
     Self::Vertex: AsPosition,
@@ -68,12 +88,26 @@ where
             + AsStorage<Vertex<Self>>
             + Consistent
             + Parametric<Data = Self>;
+
+    fn weighted_normal<B>(
+        vertex: VertexView<B>,
+        weight: NormalWeight,
+    ) -> Result<Vector<VertexPosition<Self>>, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Self>>
+            + AsStorage<Face<Self>>
+            + AsStorage<Vertex<Self>>
+            + Consistent
+            + Parametric<Data = Self>;
 }
 
 impl<G> VertexNormal for G
 where
     G: FaceNormal,
     G::Vertex: AsPosition,
+    Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    VertexPosition<G>: EuclideanSpace,
 {
     fn normal<B>(vertex: VertexView<B>) -> Result<Vector<VertexPosition<Self>>, GraphError>
     where
@@ -94,6 +128,312 @@ where
         .normalize()
         .ok_or(GraphError::Geometry)
     }
+
+    fn weighted_normal<B>(
+        vertex: VertexView<B>,
+        weight: NormalWeight,
+    ) -> Result<Vector<VertexPosition<Self>>, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Self>>
+            + AsStorage<Face<Self>>
+            + AsStorage<Vertex<Self>>
+            + Consistent
+            + Parametric<Data = Self>,
+    {
+        vertex
+            .incoming_arcs()
+            .filter(|arc| !arc.is_boundary_arc())
+            .map(|arc| corner_normal(arc, weight))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .fold(Zero::zero(), |sum: Vector<VertexPosition<Self>>, normal| sum + normal)
+            .normalize()
+            .ok_or(GraphError::Geometry)
+    }
+}
+
+// Gets the normal of the face of the given arc scaled by the weight of the
+// corner formed by the arc and its next arc. Weights are not normalized.
+pub(in crate::graph) fn corner_normal<B, G>(
+    arc: ArcView<B>,
+    weight: NormalWeight,
+) -> Result<Vector<VertexPosition<G>>, GraphError>
+where
+    B: Reborrow,
+    B::Target: AsStorage<Arc<G>>
+        + AsStorage<Face<G>>
+        + AsStorage<Vertex<G>>
+        + Consistent
+        + Parametric<Data = G>,
+    G: FaceNormal,
+    G::Vertex: AsPosition,
+    Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    VertexPosition<G>: EuclideanSpace,
+{
+    let face = arc.face().ok_or(GraphError::TopologyNotFound)?;
+    let normal = <G as FaceNormal>::normal(face)?;
+    match weight {
+        NormalWeight::Uniform => Ok(normal),
+        NormalWeight::Area => Ok(normal * <G as FaceArea>::area(face)?),
+        NormalWeight::Angle => {
+            let p = *arc.destination_vertex().position();
+            let a = *arc.source_vertex().position() - p;
+            let b = *arc.next_arc().destination_vertex().position() - p;
+            Ok(normal * angle(a, b))
+        }
+    }
+}
+
+// Gets the unsigned angle between two vectors in the interval `[0, pi]`.
+pub(in crate::graph) fn angle<S>(a: Vector<S>, b: Vector<S>) -> Scalar<S>
+where
+    S: EuclideanSpace,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    Real::atan2(a.cross(b).magnitude(), a.dot(b))
+}
+
+/// Discrete curvature of a surface at a vertex.
+///
+/// See [`VertexCurvature`].
+#[derive(Clone, Copy)]
+pub struct Curvature<S>
+where
+    S: EuclideanSpace,
+{
+    /// Gaussian curvature $K$ computed from the angle defect.
+    pub gaussian: Scalar<S>,
+    /// Mean curvature $H$ computed from the cotangent Laplacian.
+    ///
+    /// Mean curvature is positive where the surface bends away from its
+    /// normal, such as on a sphere with outward normals.
+    pub mean: Scalar<S>,
+    /// Principal curvatures $(\kappa_1,\kappa_2)$, where
+    /// $\kappa_1\geq\kappa_2$.
+    pub principal: (Scalar<S>, Scalar<S>),
+    /// Principal directions corresponding to the principal curvatures.
+    ///
+    /// These unit vectors are orthogonal to each other and to the normal of
+    /// the vertex. Principal directions are arbitrary where the surface is
+    /// umbilic (e.g., flat or spherical).
+    pub directions: (Vector<S>, Vector<S>),
+}
+
+/// Deviation of the surface of a graph from another surface.
+///
+/// Deviation is measured from points sampled on a surface to the closest
+/// points on another surface. See [`MeshGraph::deviation_from`].
+///
+/// [`MeshGraph::deviation_from`]: crate::graph::MeshGraph::deviation_from
+#[derive(Clone, Copy)]
+pub struct Deviation<S>
+where
+    S: EuclideanSpace,
+{
+    /// Hausdorff distance, which is the greatest distance from any sample to
+    /// the other surface.
+    pub hausdorff: Scalar<S>,
+    /// Mean distance from samples to the other surface weighted by area.
+    pub mean: Scalar<S>,
+    /// Root mean square (RMS) distance from samples to the other surface
+    /// weighted by area.
+    pub rms: Scalar<S>,
+}
+
+impl<S> Deviation<S>
+where
+    S: EuclideanSpace,
+{
+    // Computes the deviation from samples with weights (areas) and distances.
+    pub(in crate::graph) fn from_samples<I>(samples: I) -> Result<Self, GraphError>
+    where
+        I: IntoIterator<Item = (Scalar<S>, Scalar<S>)>,
+    {
+        let zero = Scalar::<S>::zero();
+        let (hausdorff, area, sum, squares) = samples.into_iter().fold(
+            (zero, zero, zero, zero),
+            |(hausdorff, area, sum, squares), (weight, distance)| {
+                (
+                    if distance > hausdorff {
+                        distance
+                    }
+                    else {
+                        hausdorff
+                    },
+                    area + weight,
+                    sum + (weight * distance),
+                    squares + (weight * distance * distance),
+                )
+            },
+        );
+        if area.is_zero() {
+            return Err(GraphError::Geometry);
+        }
+        Ok(Deviation {
+            hausdorff,
+            mean: sum / area,
+            rms: Real::sqrt(squares / area),
+        })
+    }
+}
+
+/// Discrete curvature of vertices.
+///
+/// Curvature is estimated from the triangles formed by each corner of the faces
+/// adjacent to a vertex using the operators described by Meyer et al. in
+/// _Discrete Differential-Geometry Operators for Triangulated 2-Manifolds_.
+/// Gaussian curvature is the angle defect and mean curvature is derived from
+/// the cotangent Laplacian, both normalized by the mixed Voronoi area of the
+/// vertex. Principal directions are derived from a least squares fit of a
+/// curvature tensor to the normal curvatures along adjacent edges. Graphs
+/// should be triangulated for accurate results.
+pub trait VertexCurvature: VertexNormal
+where
+    Self::Vertex: AsPosition,
+{
+    fn curvature<B>(vertex: VertexView<B>) -> Result<Curvature<VertexPosition<Self>>, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Self>>
+            + AsStorage<Face<Self>>
+            + AsStorage<Vertex<Self>>
+            + Consistent
+            + Parametric<Data = Self>;
+}
+
+impl<G> VertexCurvature for G
+where
+    G: VertexNormal,
+    G::Vertex: AsPosition,
+    Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    fn curvature<B>(vertex: VertexView<B>) -> Result<Curvature<VertexPosition<Self>>, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Self>>
+            + AsStorage<Face<Self>>
+            + AsStorage<Vertex<Self>>
+            + Consistent
+            + Parametric<Data = Self>,
+    {
+        let zero = Scalar::<VertexPosition<G>>::zero();
+        let one = Scalar::<VertexPosition<G>>::one();
+        let two = one + one;
+        let eight = two * two * two;
+        let pi = <Scalar<VertexPosition<G>> as Real>::PI;
+        let cotangent = |a: Vector<VertexPosition<G>>, b: Vector<VertexPosition<G>>| {
+            let sine = a.cross(b).magnitude();
+            if sine.is_zero() {
+                Err(GraphError::Geometry)
+            }
+            else {
+                Ok(a.dot(b) / sine)
+            }
+        };
+
+        let p = *vertex.position();
+        let normal = G::weighted_normal(vertex.to_ref(), NormalWeight::Angle)?;
+        let mut area = zero;
+        let mut sum = zero;
+        let mut is_boundary = false;
+        let mut laplacian = Vector::<VertexPosition<G>>::zero();
+        for arc in vertex.incoming_arcs() {
+            if arc.is_boundary_arc() {
+                is_boundary = true;
+                continue;
+            }
+            let a = *arc.next_arc().destination_vertex().position();
+            let b = *arc.source_vertex().position();
+            let (pa, pb) = (a - p, b - p);
+            // Cotangents of the angles opposite of the edges `pb` and `pa`.
+            let cot_a = cotangent(p - a, b - a)?;
+            let cot_b = cotangent(p - b, a - b)?;
+            laplacian = laplacian + ((p - a) * cot_b) + ((p - b) * cot_a);
+            sum = sum + angle(pa, pb);
+            // Accumulate the mixed Voronoi area.
+            let triangle = pa.cross(pb).magnitude() / two;
+            if pa.dot(pb) < zero {
+                area = area + (triangle / two);
+            }
+            else if cot_a < zero || cot_b < zero {
+                area = area + (triangle / (two * two));
+            }
+            else {
+                area = area + (((pb.dot(pb) * cot_a) + (pa.dot(pa) * cot_b)) / eight);
+            }
+        }
+        if area.is_zero() {
+            return Err(GraphError::Geometry);
+        }
+        // The angle defect of a boundary vertex is measured with respect to a
+        // half disk rather than a disk.
+        let defect = (if is_boundary { pi } else { two * pi }) - sum;
+        let gaussian = defect / area;
+        let mean = (laplacian * (one / (two * area))).dot(normal) / two;
+        let discriminant = (mean * mean) - gaussian;
+        let discriminant = if discriminant < zero {
+            zero
+        }
+        else {
+            discriminant.sqrt()
+        };
+        // Fit a symmetric tensor $\begin{bmatrix}a&b\\b&c\end{bmatrix}$ in
+        // a basis of the tangent plane to the normal curvatures along adjacent
+        // edges by solving the normal equations of the least squares problem.
+        let mut basis = None;
+        let mut m = [[zero; 3]; 3];
+        let mut r = [zero; 3];
+        for q in vertex.adjacent_vertices().map(|vertex| *vertex.position()) {
+            let d = q - p;
+            let tangent = match (d - (normal * normal.dot(d))).normalize() {
+                Some(tangent) => tangent,
+                _ => continue,
+            };
+            let (e1, e2) = *basis.get_or_insert_with(|| (tangent, normal.cross(tangent)));
+            let kappa = -(two * d.dot(normal)) / d.dot(d);
+            let (x, y) = (tangent.dot(e1), tangent.dot(e2));
+            let row = [x * x, two * x * y, y * y];
+            for ((mi, ri), xi) in m.iter_mut().zip(r.iter_mut()).zip(row.iter()) {
+                for (mij, xj) in mi.iter_mut().zip(row.iter()) {
+                    *mij = *mij + (*xi * *xj);
+                }
+                *ri = *ri + (*xi * kappa);
+            }
+        }
+        let (e1, e2) = basis.ok_or(GraphError::Geometry)?;
+        let determinant = |m: &[[Scalar<VertexPosition<G>>; 3]; 3]| {
+            (m[0][0] * ((m[1][1] * m[2][2]) - (m[1][2] * m[2][1])))
+                - (m[0][1] * ((m[1][0] * m[2][2]) - (m[1][2] * m[2][0])))
+                + (m[0][2] * ((m[1][0] * m[2][1]) - (m[1][1] * m[2][0])))
+        };
+        let denominator = determinant(&m);
+        if denominator.is_zero() {
+            return Err(GraphError::Geometry);
+        }
+        // Solve for the coefficients of the tensor using Cramer's rule.
+        let mut coefficients = [zero; 3];
+        for (n, coefficient) in coefficients.iter_mut().enumerate() {
+            let mut mn = m;
+            for (row, value) in mn.iter_mut().zip(r.iter()) {
+                row[n] = *value;
+            }
+            *coefficient = determinant(&mn) / denominator;
+        }
+        let [a, b, c] = coefficients;
+        // The direction of maximum curvature is the eigenvector of the
+        // largest eigenvalue of the tensor.
+        let phi = Real::atan2(two * b, a - c) / two;
+        let d1 = (e1 * phi.cos()) + (e2 * phi.sin());
+        let d2 = normal.cross(d1);
+        Ok(Curvature {
+            gaussian,
+            mean,
+            principal: (mean + discriminant, mean - discriminant),
+            directions: (d1, d2),
+        })
+    }
 }
 
 pub trait ArcNormal: GraphData
@@ -246,6 +586,55 @@ where
     }
 }
 
+/// Area of faces.
+///
+/// The area of a face is computed from a triangle fan about its centroid. This
+/// is exact for planar faces, including concave faces.
+pub trait FaceArea: GraphData
+where
+    Self::Vertex: AsPosition,
+{
+    fn area<B, T>(ring: T) -> Result<Scalar<VertexPosition<Self>>, GraphError>
+    where
+        B: Reborrow,
+        B::Target:
+            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+        T: ToRing<B>;
+}
+
+impl<G> FaceArea for G
+where
+    G: FaceCentroid + GraphData,
+    G::Vertex: AsPosition,
+    Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    VertexPosition<G>: EuclideanSpace,
+{
+    fn area<B, T>(ring: T) -> Result<Scalar<VertexPosition<Self>>, GraphError>
+    where
+        B: Reborrow,
+        B::Target:
+            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+        T: ToRing<B>,
+    {
+        let positions = ring
+            .into_ring()
+            .vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<SmallVec<[_; 4]>>();
+        let c = VertexPosition::<G>::centroid(positions.iter().cloned()).expect_consistent();
+        // The magnitude of the sum of the (vector) areas of the triangles in
+        // the fan is twice the area of the face.
+        let area = positions
+            .iter()
+            .map(|position| *position - c)
+            .perimeter()
+            .map(|(a, b)| a.cross(b))
+            .fold(Zero::zero(), |sum: Vector<VertexPosition<G>>, area| sum + area);
+        let two = Scalar::<VertexPosition<G>>::one() + One::one();
+        Ok(area.magnitude() / two)
+    }
+}
+
 pub trait FacePlane: GraphData
 where
     Self::Vertex: AsPosition,
@@ -252,12 +252,14 @@ mod edge;
 mod face;
 mod geometry;
 mod mutation;
+mod parameterize;
 mod path;
+mod sample;
 mod vertex;
 
 use decorum::cmp::IntrinsicOrd;
 use decorum::R64;
-use num::{Integer, NumCast, ToPrimitive, Unsigned};
+use num::{Integer, NumCast, One, ToPrimitive, Unsigned, Zero};
 use smallvec::SmallVec;
 use std::borrow::Borrow;
 use std::collections::{HashMap, HashSet};
@@ -267,12 +269,13 @@ use std::hash::Hash;
 use std::iter::FromIterator;
 use std::mem;
 use std::vec;
-use theon::adjunct::Map;
+use theon::adjunct::{Adjunct, IntoItems, Map};
+use theon::ops::{Cross, Dot};
 use theon::query::Aabb;
-use theon::space::{EuclideanSpace, Scalar};
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
 use theon::{AsPosition, AsPositionMut};
 use thiserror::Error;
-use typenum::NonZero;
+use typenum::{NonZero, U3};
 
 use crate::buffer::{BufferError, FromRawBuffers, FromRawBuffersWithArity, MeshBuffer};
 use crate::builder::{Buildable, FacetBuilder, MeshBuilder, SurfaceBuilder};
@@ -283,6 +286,9 @@ use crate::entity::storage::prelude::*;
 use crate::entity::storage::{AsStorage, AsStorageMut, AsStorageOf, Key, StorageTarget};
 use crate::entity::view::{Bind, Orphan, View};
 use crate::entity::{Entity, EntityError, Payload};
+use crate::geometry::bvh::{Bvh, ClosestPoint};
+use crate::geometry::kdtree::KdTree;
+use crate::geometry::tangent::{self, Corner, Tangent};
 use crate::geometry::{FromGeometry, IntoGeometry};
 use crate::graph::builder::GraphBuilder;
 use crate::graph::core::{Core, OwnedCore};
@@ -291,22 +297,27 @@ use crate::graph::edge::{Arc, Edge};
 use crate::graph::face::Face;
 use crate::graph::mutation::face::FaceInsertCache;
 use crate::graph::mutation::{Consistent, Immediate};
+use crate::graph::sample::Triangle;
 use crate::graph::vertex::Vertex;
 use crate::index::{Flat, FromIndexer, Grouping, HashIndexer, IndexBuffer, IndexVertices, Indexer};
 use crate::primitive::decompose::IntoVertices;
-use crate::primitive::{IntoPolygons, Polygonal, UnboundedPolygon};
+use crate::primitive::{self, IntoPolygons, Polygonal, UnboundedPolygon};
 use crate::transact::Transact;
 use crate::{DynamicArity, MeshArity, StaticArity};
 
 pub use crate::entity::view::{ClosedView, Rebind};
 pub use crate::graph::data::GraphData;
-pub use crate::graph::edge::{ArcKey, ArcOrphan, ArcView, EdgeKey, EdgeOrphan, EdgeView, ToArc};
+pub use crate::graph::edge::{
+    ArcKey, ArcOrphan, ArcView, EdgeFeature, EdgeKey, EdgeOrphan, EdgeView, ToArc,
+};
 pub use crate::graph::face::{FaceKey, FaceOrphan, FaceView, Ring, ToRing};
 pub use crate::graph::geometry::{
-    ArcNormal, EdgeMidpoint, FaceCentroid, FaceNormal, FacePlane, VertexCentroid, VertexNormal,
-    VertexPosition,
+    ArcNormal, Curvature, Deviation, EdgeMidpoint, FaceArea, FaceCentroid, FaceNormal,
+    FacePlane, NormalWeight, VertexCentroid, VertexCurvature, VertexNormal, VertexPosition,
 };
+pub use crate::graph::parameterize::{Boundary, Parameterization};
 pub use crate::graph::path::Path;
+pub use crate::graph::sample::Sample;
 pub use crate::graph::vertex::{VertexKey, VertexOrphan, VertexView};
 
 pub use Selector::ByIndex;
@@ -791,96 +802,953 @@ where
     ///
     /// # Errors
     ///
-    /// Returns an error if a vertex is not found or the path is malformed.
-    pub fn path_mut<I>(&mut self, keys: I) -> Result<Path<'static, &mut Self>, GraphError>
-    where
-        I: IntoIterator,
-        I::Item: Borrow<VertexKey>,
-    {
-        Path::bind(self, keys)
-    }
-
-    /// Gets an axis-aligned bounding box that encloses the graph.
-    pub fn aabb(&self) -> Aabb<VertexPosition<G>>
+    /// Returns an error if a vertex is not found or the path is malformed.
+    pub fn path_mut<I>(&mut self, keys: I) -> Result<Path<'static, &mut Self>, GraphError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<VertexKey>,
+    {
+        Path::bind(self, keys)
+    }
+
+    /// Gets an axis-aligned bounding box that encloses the graph.
+    pub fn aabb(&self) -> Aabb<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Scalar<VertexPosition<G>>: IntrinsicOrd,
+    {
+        Aabb::from_points(self.vertices().map(|vertex| *vertex.position()))
+    }
+
+    /// Builds a bounding volume hierarchy over the faces of the graph.
+    ///
+    /// Faces are decomposed into triangle fans. The data associated with each
+    /// triangle is the key of its face and the keys of its vertices, which
+    /// correspond to the barycentric coordinates of ray hits.
+    ///
+    /// # Examples
+    ///
+    /// Casting a ray against a cube:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::{Point3, Vector3};
+    /// use plexus::geometry::{Ray, Unit};
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// let graph: MeshGraph<Point3<f64>> = Cube::new()
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .collect();
+    /// let bvh = graph.bvh();
+    ///
+    /// let ray = Ray {
+    ///     origin: Point3::new(0.0, 0.0, 2.0),
+    ///     direction: Unit::try_from_inner(-Vector3::z()).unwrap(),
+    /// };
+    /// let hit = bvh.cast_ray(&ray).unwrap();
+    /// let (key, _) = hit.data;
+    /// assert_eq!(1.5, hit.distance);
+    /// assert_eq!(Vector3::z(), graph.face(key).unwrap().normal().unwrap());
+    /// ```
+    pub fn bvh(&self) -> Bvh<(FaceKey, [VertexKey; 3]), VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        Bvh::from_triangles(self.triangles())
+    }
+
+    // Decomposes faces into triangles about their leading vertex.
+    fn triangles(&self) -> Vec<Triangle<VertexPosition<G>>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.faces()
+            .flat_map(|face| {
+                let key = face.key();
+                let vertices = face
+                    .adjacent_vertices()
+                    .map(|vertex| (vertex.key(), *vertex.position()))
+                    .collect::<SmallVec<[_; 4]>>();
+                let (ka, a) = vertices[0];
+                vertices[1..]
+                    .iter()
+                    .zip(vertices[2..].iter())
+                    .map(|(&(kb, b), &(kc, c))| ((key, [ka, kb, kc]), [a, b, c]))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Gets pairs of non-adjacent faces that intersect.
+    ///
+    /// Faces are adjacent if they share a vertex. Faces are decomposed into
+    /// triangle fans and are culled using a bounding volume hierarchy before
+    /// triangles are tested for intersection. Coplanar faces that overlap are
+    /// considered intersecting. Each pair is reported once in no particular
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    ///
+    /// // Two triangles that pierce each other.
+    /// let graph = MeshGraph::<Point3<f64>>::from_raw_buffers_with_arity(
+    ///     vec![0usize, 1, 2, 3, 4, 5],
+    ///     vec![
+    ///         (0.0, 0.0, 0.0),
+    ///         (2.0, 0.0, 0.0),
+    ///         (0.0, 2.0, 0.0),
+    ///         (0.5, 0.5, -1.0),
+    ///         (0.5, 0.5, 1.0),
+    ///         (0.5, -1.0, 0.0),
+    ///     ],
+    ///     3,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(1, graph.self_intersections().len());
+    /// ```
+    pub fn self_intersections(&self) -> Vec<(FaceKey, FaceKey)>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        let vertices = self
+            .faces()
+            .map(|face| {
+                let keys = face
+                    .adjacent_vertices()
+                    .map(|vertex| vertex.key())
+                    .collect::<SmallVec<[_; 4]>>();
+                (face.key(), keys)
+            })
+            .collect::<HashMap<_, _>>();
+        let bvh = self.bvh();
+        let mut pairs = HashSet::new();
+        for ((face, _), triangle) in bvh.triangles() {
+            for ((other, _), candidate) in bvh.overlapping(triangle) {
+                if face == other
+                    || pairs.contains(&(*face, *other))
+                    || pairs.contains(&(*other, *face))
+                    || vertices[face]
+                        .iter()
+                        .any(|key| vertices[other].contains(key))
+                {
+                    continue;
+                }
+                if primitive::intersect_triangles(*triangle, *candidate).is_some() {
+                    pairs.insert((*face, *other));
+                }
+            }
+        }
+        pairs.into_iter().collect()
+    }
+
+    /// Measures the deviation of the surface of the graph from the surface of
+    /// another graph.
+    ///
+    /// Points are sampled on the faces of the graph and the distances to the
+    /// closest points on the faces of the other graph are aggregated. This is
+    /// one-sided: the deviation of a surface from a larger surface that
+    /// contains it is zero. See [`MeshGraph::symmetric_deviation`].
+    ///
+    /// `count` points are sampled uniformly by area as with
+    /// [`MeshGraph::sample_uniform`], so the result is an estimate that is
+    /// determined by `seed`. Random samples do not alias with the structure of
+    /// regular meshes like samples on a grid. The Hausdorff distance is a lower
+    /// bound that converges as `count` increases.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either graph has no faces or if the area of the
+    /// graph is zero.
+    ///
+    /// # Examples
+    ///
+    /// Measuring the error of an approximation of a sphere:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let fine: MeshGraph<Point3<f64>> = UvSphere::new(64, 32)
+    ///     .polygons::<Position<E3>>()
+    ///     .collect();
+    /// let coarse: MeshGraph<Point3<f64>> = UvSphere::new(8, 4)
+    ///     .polygons::<Position<E3>>()
+    ///     .collect();
+    ///
+    /// let deviation = fine.deviation_from(&coarse, 4096, 0).unwrap();
+    /// assert!(deviation.hausdorff < 0.25);
+    /// assert!(deviation.mean <= deviation.rms);
+    /// ```
+    pub fn deviation_from<H>(
+        &self,
+        other: &MeshGraph<H>,
+        count: usize,
+        seed: u64,
+    ) -> Result<Deviation<VertexPosition<G>>, GraphError>
+    where
+        G::Vertex: AsPosition,
+        H: GraphData,
+        H::Vertex: AsPosition<Position = VertexPosition<G>>,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        Deviation::from_samples(self.distance_samples(&other.bvh(), count, seed)?)
+    }
+
+    /// Measures the symmetric deviation of the surfaces of two graphs.
+    ///
+    /// This combines the samples of [`MeshGraph::deviation_from`] in both
+    /// directions. The Hausdorff distance is the symmetric Hausdorff distance,
+    /// which is the greater of the one-sided distances.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either graph has no faces or if the area of either
+    /// graph is zero.
+    pub fn symmetric_deviation<H>(
+        &self,
+        other: &MeshGraph<H>,
+        count: usize,
+        seed: u64,
+    ) -> Result<Deviation<VertexPosition<G>>, GraphError>
+    where
+        G::Vertex: AsPosition,
+        H: GraphData,
+        H::Vertex: AsPosition<Position = VertexPosition<G>>,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        let mut samples = self.distance_samples(&other.bvh(), count, seed)?;
+        samples.extend(other.distance_samples(&self.bvh(), count, seed)?);
+        Deviation::from_samples(samples)
+    }
+
+    // Samples the faces of the graph uniformly by area and gets the weight
+    // (area) of each sample and its distance to the closest point in the given
+    // hierarchy.
+    #[allow(clippy::type_complexity)]
+    fn distance_samples<T>(
+        &self,
+        bvh: &Bvh<T, VertexPosition<G>>,
+        count: usize,
+        seed: u64,
+    ) -> Result<Vec<(Scalar<VertexPosition<G>>, Scalar<VertexPosition<G>>)>, GraphError>
+    where
+        G::Vertex: AsPosition,
+        T: Copy,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        let triangles = self.triangles();
+        if triangles.is_empty() {
+            return Err(GraphError::TopologyNotFound);
+        }
+        let count = count.max(1);
+        let area = triangles
+            .iter()
+            .map(|(_, [a, b, c])| (*b - *a).cross(*c - *a).magnitude())
+            .fold(Zero::zero(), |sum: Scalar<VertexPosition<G>>, area| sum + area)
+            / into_scalar::<Scalar<VertexPosition<G>>>(2.0)?;
+        // Samples are drawn in proportion to area, so each sample represents
+        // an equal share of the area of the graph.
+        let weight = area / into_scalar::<Scalar<VertexPosition<G>>>(count as f64)?;
+        sample::uniform(&triangles, count, seed)?
+            .into_iter()
+            .map(|sample| {
+                bvh.closest_point(sample.point)
+                    .map(|closest| (weight, closest.distance))
+                    .ok_or(GraphError::TopologyNotFound)
+            })
+            .collect()
+    }
+
+    /// Builds a $k$-d tree over the vertices of the graph.
+    ///
+    /// The data associated with each point in the tree is the key of its
+    /// vertex.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// let graph: MeshGraph<Point3<f64>> = UvSphere::new(16, 8)
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .collect();
+    /// let tree = graph.kdtree();
+    ///
+    /// // Find the vertices within a small distance of the poles.
+    /// let keys = tree
+    ///     .within(Point3::new(0.0, 0.0, 1.0), 0.1)
+    ///     .into_iter()
+    ///     .chain(tree.within(Point3::new(0.0, 0.0, -1.0), 0.1))
+    ///     .map(|neighbor| neighbor.data)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(2, keys.len());
+    /// ```
+    pub fn kdtree(&self) -> KdTree<VertexKey, VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: Adjunct<Item = Scalar<VertexPosition<G>>> + IntoItems,
+    {
+        KdTree::from_points(self.vertices().map(|vertex| (vertex.key(), *vertex.position())))
+    }
+
+    /// Gets the closest point on the faces of the graph to a query point.
+    ///
+    /// This builds a bounding volume hierarchy for each query. To query many
+    /// points, use [`MeshGraph::bvh`] and [`Bvh::closest_point`] instead.
+    ///
+    /// Returns `None` if the graph has no faces.
+    ///
+    /// [`Bvh::closest_point`]: crate::geometry::bvh::Bvh::closest_point
+    pub fn closest_point(
+        &self,
+        point: VertexPosition<G>,
+    ) -> Option<ClosestPoint<(FaceKey, [VertexKey; 3]), VertexPosition<G>>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        self.bvh().closest_point(point)
+    }
+
+    /// Gets the signed distance from a query point to the surface of the
+    /// graph.
+    ///
+    /// See [`MeshGraph::signed_distance_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not closed or has no faces.
+    pub fn signed_distance(
+        &self,
+        point: VertexPosition<G>,
+    ) -> Result<Scalar<VertexPosition<G>>, GraphError>
+    where
+        G: VertexNormal,
+        G::Vertex: AsPosition,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        self.signed_distance_with(&self.bvh(), point)
+    }
+
+    /// Gets the signed distance from a query point to the surface of the
+    /// graph using a bounding volume hierarchy built by [`MeshGraph::bvh`].
+    ///
+    /// The distance is negative if the point is inside of the surface and
+    /// positive otherwise, assuming that the faces of the graph are wound such
+    /// that their normals point outward. The sign is determined from the
+    /// angle-weighted pseudo-normal of the face, edge, or vertex nearest to the
+    /// point as described by Bærentzen and Aanæs in _Signed Distance
+    /// Computation Using the Angle Weighted Pseudonormal_.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not closed or has no faces or if the
+    /// hierarchy does not describe the graph.
+    pub fn signed_distance_with(
+        &self,
+        bvh: &Bvh<(FaceKey, [VertexKey; 3]), VertexPosition<G>>,
+        point: VertexPosition<G>,
+    ) -> Result<Scalar<VertexPosition<G>>, GraphError>
+    where
+        G: VertexNormal,
+        G::Vertex: AsPosition,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        if self.arcs().any(|arc| arc.is_boundary_arc()) {
+            return Err(GraphError::TopologyMalformed);
+        }
+        let closest = bvh
+            .closest_point(point)
+            .ok_or(GraphError::TopologyNotFound)?;
+        let (face, keys) = closest.data;
+        let (u, v, w) = closest.barycentric;
+        // Barycentric coordinates are zero for vertices opposite to the edge or
+        // vertex on which the closest point lies.
+        let features = keys
+            .iter()
+            .zip([u, v, w])
+            .filter(|(_, x)| !x.is_zero())
+            .map(|(key, _)| *key)
+            .collect::<SmallVec<[_; 3]>>();
+        let face = self.face(face).ok_or(GraphError::TopologyNotFound)?;
+        let normal = match features.as_slice() {
+            [key] => self
+                .vertex(*key)
+                .ok_or(GraphError::TopologyNotFound)?
+                .weighted_normal(NormalWeight::Angle)?,
+            // If the vertices are not connected by an edge, then the closest
+            // point lies on a diagonal of the face.
+            [a, b] => match self.arc((*a, *b).into()) {
+                Some(arc) => {
+                    let a = arc.face().ok_or(GraphError::TopologyMalformed)?.normal()?;
+                    let b = arc
+                        .opposite_arc()
+                        .face()
+                        .ok_or(GraphError::TopologyMalformed)?
+                        .normal()?;
+                    a + b
+                }
+                _ => face.normal()?,
+            },
+            _ => face.normal()?,
+        };
+        Ok(if (point - closest.point).dot(normal) < Zero::zero() {
+            -closest.distance
+        }
+        else {
+            closest.distance
+        })
+    }
+
+    /// Samples points uniformly by area from the faces of the graph.
+    ///
+    /// Samples are drawn from a generator seeded with `seed`, so the same seed
+    /// always yields the same samples for a given graph. See [`Sample`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the faces of the graph have no area.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// let graph: MeshGraph<Point3<f64>> = UvSphere::new(16, 8)
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .collect();
+    /// let samples = graph.sample_uniform(64, 0).unwrap();
+    /// assert!(samples
+    ///     .iter()
+    ///     .all(|sample| graph.face(sample.face).is_some()));
+    /// ```
+    pub fn sample_uniform(
+        &self,
+        count: usize,
+        seed: u64,
+    ) -> Result<Vec<Sample<VertexPosition<G>>>, GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        sample::uniform(&self.triangles(), count, seed)
+    }
+
+    /// Samples points from the faces of the graph with Poisson disk spacing.
+    ///
+    /// No two samples are closer than `radius`. Distance is measured in the
+    /// embedding space rather than along the surface. Samples are drawn by
+    /// rejecting uniform candidates, so the number of samples depends on the
+    /// area of the graph and the radius. As with
+    /// [`sample_uniform`][`MeshGraph::sample_uniform`], samples are determined
+    /// by `seed`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `radius` is not positive, the faces of the graph
+    /// have no area, or `radius` is so small relative to the area of the graph
+    /// that more than $2^{24}$ candidates would be drawn.
+    pub fn sample_poisson_disk<T>(
+        &self,
+        radius: T,
+        seed: u64,
+    ) -> Result<Vec<Sample<VertexPosition<G>>>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        sample::poisson_disk(&self.triangles(), radius.into(), seed)
+    }
+
+    /// Gets the total surface area of the faces in the graph.
+    pub fn area(&self) -> Scalar<VertexPosition<G>>
+    where
+        G: FaceArea,
+        G::Vertex: AsPosition,
+    {
+        self.faces()
+            .map(|face| face.area())
+            .fold(Zero::zero(), |sum, area| sum + area)
+    }
+
+    /// Gets the signed volume enclosed by the graph.
+    ///
+    /// The volume is positive if the faces of the graph are wound such that
+    /// their normals point outward.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not closed (i.e., has boundary arcs).
+    pub fn volume(&self) -> Result<Scalar<VertexPosition<G>>, GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        Ok(self
+            .tetrahedra()?
+            .into_iter()
+            .fold(Zero::zero(), |sum, (volume, _)| sum + volume))
+    }
+
+    /// Gets the center of mass of the solid enclosed by the graph, assuming
+    /// uniform density.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not closed or encloses no volume.
+    pub fn center_of_mass(&self) -> Result<VertexPosition<G>, GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        let (volume, moment) = self.tetrahedra()?.into_iter().fold(
+            (Zero::zero(), Zero::zero()),
+            |(volume, moment): (Scalar<VertexPosition<G>>, Vector<VertexPosition<G>>),
+             (v, [a, b, c])| (volume + v, moment + ((a + b + c) * v)),
+        );
+        if volume.is_zero() {
+            return Err(GraphError::Geometry);
+        }
+        let four = into_scalar::<Scalar<VertexPosition<G>>>(4.0)?;
+        Ok(VertexPosition::<G>::origin() + (moment * (One::one() / (volume * four))))
+    }
+
+    /// Gets the inertia tensor of the solid enclosed by the graph about its
+    /// center of mass, assuming a uniform density of one.
+    ///
+    /// The tensor is given as rows of a symmetric $3\times3$ matrix with
+    /// respect to the axes of the space. Scale the tensor by a density to get
+    /// the tensor for a particular material.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not closed or encloses no volume.
+    pub fn inertia_tensor(&self) -> Result<[[Scalar<VertexPosition<G>>; 3]; 3], GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        fn xyz<T>((x, y, z): (T, T, T)) -> [T; 3] {
+            [x, y, z]
+        }
+
+        let zero = Scalar::<VertexPosition<G>>::zero();
+        let twenty = into_scalar::<Scalar<VertexPosition<G>>>(20.0)?;
+        // Accumulate the covariance of the solid about the origin. The
+        // covariance of a tetrahedron with a vertex at the origin is
+        // $\frac{V}{20}(\sum_i x_ix_i^T+ss^T)$, where $s$ is the sum of the
+        // vertices $x_i$ and $V$ is the signed volume.
+        let mut covariance = [[zero; 3]; 3];
+        let mut volume = zero;
+        let mut moment = [zero; 3];
+        for (v, vertices) in self.tetrahedra()? {
+            let vertices = vertices.map(|vertex| xyz(vertex.into_xyz()));
+            let mut s = [zero; 3];
+            for vertex in vertices.iter() {
+                for (s, x) in s.iter_mut().zip(vertex.iter()) {
+                    *s = *s + *x;
+                }
+            }
+            for (i, row) in covariance.iter_mut().enumerate() {
+                for (j, entry) in row.iter_mut().enumerate() {
+                    let sum = vertices
+                        .iter()
+                        .fold(s[i] * s[j], |sum, vertex| sum + (vertex[i] * vertex[j]));
+                    *entry = *entry + ((sum * v) / twenty);
+                }
+            }
+            volume = volume + v;
+            for (moment, s) in moment.iter_mut().zip(s.iter()) {
+                *moment = *moment + (*s * v);
+            }
+        }
+        if volume.is_zero() {
+            return Err(GraphError::Geometry);
+        }
+        // Translate the covariance to the center of mass $c$ by subtracting
+        // $Vcc^T$, where $Vc$ is four times the accumulated moment.
+        let four = into_scalar::<Scalar<VertexPosition<G>>>(4.0)?;
+        let c = moment.map(|moment| moment / (volume * four));
+        for (i, row) in covariance.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = *entry - (volume * c[i] * c[j]);
+            }
+        }
+        let trace = covariance[0][0] + covariance[1][1] + covariance[2][2];
+        let mut tensor = [[zero; 3]; 3];
+        for (i, (row, covariance)) in tensor.iter_mut().zip(covariance.iter()).enumerate() {
+            for (j, (entry, covariance)) in row.iter_mut().zip(covariance.iter()).enumerate() {
+                let diagonal = if i == j { trace } else { zero };
+                *entry = diagonal - *covariance;
+            }
+        }
+        Ok(tensor)
+    }
+
+    // Decomposes the solid enclosed by the graph into tetrahedra formed by the
+    // origin and triangle fans of each face. Yields the signed volume and the
+    // remaining vertices (relative to the origin) of each tetrahedron.
+    #[allow(clippy::type_complexity)]
+    fn tetrahedra(
+        &self,
+    ) -> Result<Vec<(Scalar<VertexPosition<G>>, [Vector<VertexPosition<G>>; 3])>, GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        if self.arcs().any(|arc| arc.is_boundary_arc()) {
+            return Err(GraphError::TopologyMalformed);
+        }
+        let six = into_scalar::<Scalar<VertexPosition<G>>>(6.0)?;
+        let origin = VertexPosition::<G>::origin();
+        let mut tetrahedra = Vec::with_capacity(self.face_count());
+        for face in self.faces() {
+            let positions = face
+                .adjacent_vertices()
+                .map(|vertex| *vertex.position() - origin)
+                .collect::<SmallVec<[_; 4]>>();
+            let a = positions[0];
+            for (b, c) in positions[1..].iter().zip(positions[2..].iter()) {
+                let volume = a.dot(b.cross(*c)) / six;
+                tetrahedra.push((volume, [a, *b, *c]));
+            }
+        }
+        Ok(tetrahedra)
+    }
+
+    // TODO: This triangulation does not consider geometry and exhibits some
+    //       bad behavior in certain situations. Triangulation needs to be
+    //       reworked and may need to expose a bit more complexity. A geometric
+    //       triangulation algorithm would be a useful addition and could
+    //       detect concave faces and provide more optimal splits. See comments
+    //       on `FaceView::triangulate`.
+    /// Triangulates the graph, tessellating all faces into triangles.
+    pub fn triangulate(&mut self) {
+        // TODO: This implementation is a bit fragile and depends on the
+        //       semantics of `TopologyConflict` in this context. It also panics
+        //       if no valid split is found given all offsets or if some other
+        //       error is encountered while splitting. Can this code assume that
+        //       any of these conditions aren't possible? This should work a bit
+        //       better than using `FaceView::triangulate` until triangulation
+        //       is reworked.
+        let keys = self
+            .core
+            .faces
+            .iter()
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+        for key in keys {
+            let mut face = self.face_mut(key).unwrap();
+            let mut offset = 0;
+            while face.arity() > 3 {
+                match face.split(ByIndex(offset), ByIndex(offset + 2)) {
+                    Ok(next) => {
+                        face = next.into_face().expect_consistent();
+                        offset = 0;
+                    }
+                    Err(GraphError::TopologyConflict) => {
+                        // Retry if the split intersected another face. See
+                        // `FaceSplitCache::from_face`.
+                        face = self.face_mut(key).unwrap();
+                        offset += 1;
+                        if offset >= face.arity() {
+                            panic!()
+                        }
+                    }
+                    _ => panic!(),
+                }
+            }
+        }
+    }
+
+    /// Smooths the positions of vertices in the graph.
+    ///
+    /// Each position is translated by its offset from its centroid scaled by
+    /// the given factor. The centroid of a vertex position is the mean of the
+    /// positions of its adjacent vertices. That is, given a factor $k$ and a
+    /// vertex with position $P$ and centroid $Q$, its position becomes
+    /// $P+k(Q-P)$.
+    pub fn smooth<T>(&mut self, factor: T)
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: VertexCentroid,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let factor = factor.into();
+        let mut positions = HashMap::with_capacity(self.vertex_count());
+        for vertex in self.vertices() {
+            let position = *vertex.position();
+            positions.insert(
+                vertex.key(),
+                position + ((vertex.centroid() - position) * factor),
+            );
+        }
+        for mut vertex in self.vertex_orphans() {
+            *vertex.get_mut().as_position_mut() = positions.remove(&vertex.key()).unwrap();
+        }
+    }
+
+    /// Computes the discrete curvature of the surface at each vertex.
+    ///
+    /// See [`VertexCurvature`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the curvature of any vertex cannot be computed.
+    ///
+    /// [`VertexCurvature`]: crate::graph::VertexCurvature
+    pub fn curvatures(
+        &self,
+    ) -> Result<HashMap<VertexKey, Curvature<VertexPosition<G>>>, GraphError>
+    where
+        G: VertexCurvature,
+        G::Vertex: AsPosition,
+    {
+        self.vertices()
+            .map(|vertex| vertex.curvature().map(|curvature| (vertex.key(), curvature)))
+            .collect()
+    }
+
+    /// Gets the keys of feature edges given a threshold dihedral angle.
+    ///
+    /// Feature edges are boundary edges and sharp edges with a dihedral angle
+    /// that exceeds the given threshold. See [`EdgeView::feature`]. Graphs
+    /// cannot contain non-manifold edges; see [`index::non_manifold_edges`]
+    /// to find them in raw buffers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dihedral angle of any edge cannot be computed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// let graph: MeshGraph<Point3<f64>> = Cube::new()
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .collect();
+    /// let edges = graph.feature_edges(1.0).unwrap();
+    /// assert_eq!(12, edges.len());
+    /// ```
+    ///
+    /// [`index::non_manifold_edges`]: crate::index::non_manifold_edges
+    pub fn feature_edges<T>(&self, angle: T) -> Result<Vec<EdgeKey>, GraphError>
+    where
+        G: FaceNormal,
+        G::Vertex: AsPosition,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+        VertexPosition<G>: EuclideanSpace,
+        T: Into<Scalar<VertexPosition<G>>>,
+    {
+        let angle = angle.into();
+        let mut keys = Vec::new();
+        for edge in self.edges() {
+            if edge.feature(angle)?.is_some() {
+                keys.push(edge.key());
+            }
+        }
+        Ok(keys)
+    }
+
+    /// Classifies the edges of the graph given a threshold dihedral angle and
+    /// writes the classification into edge data.
+    ///
+    /// The given function is called with an orphan view of each edge and its
+    /// feature classification, which is `None` if the edge is not a feature.
+    /// This can be used to set or clear crease flags in edge data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dihedral angle of any edge cannot be computed.
+    /// If an error is returned, then no edge data is modified.
+    pub fn feature_edges_with<T, F>(&mut self, angle: T, mut f: F) -> Result<(), GraphError>
+    where
+        G: FaceNormal,
+        G::Vertex: AsPosition,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+        VertexPosition<G>: EuclideanSpace,
+        T: Into<Scalar<VertexPosition<G>>>,
+        F: FnMut(EdgeOrphan<G>, Option<EdgeFeature>),
+    {
+        let angle = angle.into();
+        let features = self
+            .edges()
+            .map(|edge| edge.feature(angle).map(|feature| (edge.key(), feature)))
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        for edge in self.edge_orphans() {
+            let feature = features[&edge.key()];
+            f(edge, feature);
+        }
+        Ok(())
+    }
+
+    /// Computes a parameterization of the graph and writes the resulting
+    /// coordinates into vertex data.
+    ///
+    /// The graph must be topologically equivalent to a disk: it must be
+    /// connected and have exactly one boundary. Closed surfaces can be
+    /// parameterized by first cutting seams into the graph. The given function
+    /// is called with an orphan view of each vertex and its two-dimensional
+    /// coordinates within the unit square, which can be used to assign texture
+    /// coordinates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not topologically equivalent to a disk
+    /// or if the parameterization cannot be computed from its geometry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::graph::{Boundary, MeshGraph, Parameterization};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Tetragon;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+    ///     vec![Tetragon::new(0usize, 1, 2, 3)],
+    ///     vec![
+    ///         (0.0, 0.0, 0.0),
+    ///         (2.0, 0.0, 0.0),
+    ///         (2.0, 2.0, 1.0),
+    ///         (0.0, 2.0, 1.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// let mut uvs = HashMap::new();
+    /// let parameterization = Parameterization::Tutte(Boundary::Square);
+    /// graph
+    ///     .parameterize_vertices_with(parameterization, |vertex, uv| {
+    ///         uvs.insert(vertex.key(), uv);
+    ///     })
+    ///     .unwrap();
+    /// ```
+    pub fn parameterize_vertices_with<F>(
+        &mut self,
+        parameterization: Parameterization,
+        mut f: F,
+    ) -> Result<(), GraphError>
     where
         G::Vertex: AsPosition,
-        VertexPosition<G>: EuclideanSpace,
-        Scalar<VertexPosition<G>>: IntrinsicOrd,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        F: FnMut(VertexOrphan<G>, (Scalar<VertexPosition<G>>, Scalar<VertexPosition<G>>)),
     {
-        Aabb::from_points(self.vertices().map(|vertex| *vertex.position()))
-    }
-
-    // TODO: This triangulation does not consider geometry and exhibits some
-    //       bad behavior in certain situations. Triangulation needs to be
-    //       reworked and may need to expose a bit more complexity. A geometric
-    //       triangulation algorithm would be a useful addition and could
-    //       detect concave faces and provide more optimal splits. See comments
-    //       on `FaceView::triangulate`.
-    /// Triangulates the graph, tessellating all faces into triangles.
-    pub fn triangulate(&mut self) {
-        // TODO: This implementation is a bit fragile and depends on the
-        //       semantics of `TopologyConflict` in this context. It also panics
-        //       if no valid split is found given all offsets or if some other
-        //       error is encountered while splitting. Can this code assume that
-        //       any of these conditions aren't possible? This should work a bit
-        //       better than using `FaceView::triangulate` until triangulation
-        //       is reworked.
-        let keys = self
-            .core
-            .faces
-            .iter()
-            .map(|(key, _)| key)
-            .collect::<Vec<_>>();
-        for key in keys {
-            let mut face = self.face_mut(key).unwrap();
-            let mut offset = 0;
-            while face.arity() > 3 {
-                match face.split(ByIndex(offset), ByIndex(offset + 2)) {
-                    Ok(next) => {
-                        face = next.into_face().expect_consistent();
-                        offset = 0;
-                    }
-                    Err(GraphError::TopologyConflict) => {
-                        // Retry if the split intersected another face. See
-                        // `FaceSplitCache::from_face`.
-                        face = self.face_mut(key).unwrap();
-                        offset += 1;
-                        if offset >= face.arity() {
-                            panic!()
-                        }
-                    }
-                    _ => panic!(),
-                }
-            }
+        let coordinates = parameterize::parameterize(self, parameterization)?;
+        for vertex in self.vertex_orphans() {
+            let [u, v] = coordinates[&vertex.key()];
+            f(vertex, (into_scalar(u)?, into_scalar(v)?));
         }
+        Ok(())
     }
 
-    /// Smooths the positions of vertices in the graph.
+    /// Computes a parameterization of the graph and writes the resulting
+    /// coordinates into arc data.
     ///
-    /// Each position is translated by its offset from its centroid scaled by
-    /// the given factor. The centroid of a vertex position is the mean of the
-    /// positions of its adjacent vertices. That is, given a factor $k$ and a
-    /// vertex with position $P$ and centroid $Q$, its position becomes
-    /// $P+k(Q-P)$.
-    pub fn smooth<T>(&mut self, factor: T)
+    /// This behaves like [`MeshGraph::parameterize_vertices_with`], but calls
+    /// the given function with an orphan view of each arc and the coordinates
+    /// of its destination vertex. Arcs correspond to the corners of faces,
+    /// so this is useful when coordinates are later combined with other
+    /// per-face data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not topologically equivalent to a disk
+    /// or if the parameterization cannot be computed from its geometry.
+    ///
+    /// [`MeshGraph::parameterize_vertices_with`]: crate::graph::MeshGraph::parameterize_vertices_with
+    pub fn parameterize_arcs_with<F>(
+        &mut self,
+        parameterization: Parameterization,
+        mut f: F,
+    ) -> Result<(), GraphError>
     where
-        T: Into<Scalar<VertexPosition<G>>>,
-        G: VertexCentroid,
-        G::Vertex: AsPositionMut,
-        VertexPosition<G>: EuclideanSpace,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        F: FnMut(ArcOrphan<G>, (Scalar<VertexPosition<G>>, Scalar<VertexPosition<G>>)),
     {
-        let factor = factor.into();
-        let mut positions = HashMap::with_capacity(self.vertex_count());
-        for vertex in self.vertices() {
-            let position = *vertex.position();
-            positions.insert(
-                vertex.key(),
-                position + ((vertex.centroid() - position) * factor),
-            );
-        }
-        for mut vertex in self.vertex_orphans() {
-            *vertex.get_mut().as_position_mut() = positions.remove(&vertex.key()).unwrap();
+        let coordinates = parameterize::parameterize(self, parameterization)?;
+        for arc in self.arc_orphans() {
+            let (_, b): (VertexKey, VertexKey) = arc.key().into();
+            let [u, v] = coordinates[&b];
+            f(arc, (into_scalar(u)?, into_scalar(v)?));
         }
+        Ok(())
     }
 
     /// Splits the graph along a path.
@@ -1184,6 +2052,284 @@ where
         })?;
         builder.build()
     }
+
+    /// Creates a [`Buildable`] mesh data structure from a triangulated graph
+    /// with tangent frames.
+    ///
+    /// This behaves like [`MeshGraph::to_mesh_by_face_with`], but also computes
+    /// the tangent frame of each corner of each face. The accessor function
+    /// reads the position, normal, and texture coordinates of each corner and
+    /// the given function converts a corner and its tangent frame into the
+    /// output vertex data. See the [`tangent`] module.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::{Point3, Vector3, Vector4};
+    /// use plexus::buffer::MeshBuffer3;
+    /// use plexus::geometry::tangent::Corner;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// pub struct Vertex {
+    ///     pub position: E3,
+    ///     pub normal: Vector3<f64>,
+    ///     pub tangent: Vector4<f64>,
+    /// }
+    ///
+    /// let graph: MeshGraph<E3> = UvSphere::new(16, 8)
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .triangulate()
+    ///     .collect();
+    /// let buffer: MeshBuffer3<usize, _> = graph
+    ///     .to_mesh_by_face_with_tangents(
+    ///         |_, vertex| {
+    ///             let position = *vertex.position();
+    ///             Corner {
+    ///                 position,
+    ///                 normal: vertex.normal().unwrap(),
+    ///                 uv: (position.x.atan2(position.y), position.z),
+    ///             }
+    ///         },
+    ///         |_, vertex, frame| {
+    ///             let tangent = frame.tangent;
+    ///             Vertex {
+    ///                 position: *vertex.position(),
+    ///                 normal: vertex.normal().unwrap(),
+    ///                 tangent: Vector4::new(tangent.x, tangent.y, tangent.z, frame.sign),
+    ///             }
+    ///         },
+    ///     )
+    ///     .unwrap();
+    /// for vertex in buffer.as_vertex_slice() {
+    ///     assert!(vertex.tangent.xyz().dot(&vertex.normal).abs() < 1e-6);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not triangulated, the vertex data
+    /// cannot be inserted into the output, there are arity conflicts, or the
+    /// output does not support topology found in the graph.
+    ///
+    /// [`Buildable`]: crate::builder::Buildable
+    /// [`MeshGraph::to_mesh_by_face_with`]: crate::graph::MeshGraph::to_mesh_by_face_with
+    /// [`tangent`]: crate::geometry::tangent
+    pub fn to_mesh_by_face_with_tangents<B, S, A, F>(
+        &self,
+        mut accessor: A,
+        mut f: F,
+    ) -> Result<B, GraphError>
+    where
+        B: Buildable,
+        B::Facet: FromGeometry<G::Face>,
+        GraphError: From<B::Error>,
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<S>: Cross<Output = Vector<S>>,
+        A: FnMut(FaceView<&Self>, VertexView<&Self>) -> Corner<S>,
+        F: FnMut(FaceView<&Self>, VertexView<&Self>, Tangent<S>) -> B::Vertex,
+    {
+        let mut triangles = Vec::with_capacity(self.face_count());
+        for face in self.faces() {
+            let corners = face
+                .adjacent_vertices()
+                .map(|vertex| accessor(face, vertex))
+                .collect::<SmallVec<[_; 4]>>();
+            if corners.len() != 3 {
+                return Err(GraphError::ArityConflict {
+                    expected: 3,
+                    actual: corners.len(),
+                });
+            }
+            triangles.push([corners[0], corners[1], corners[2]]);
+        }
+        let frames = tangent::tangents(triangles);
+        let mut builder = B::builder();
+        builder.surface_with(|builder| {
+            for (face, frames) in self.faces().zip(frames) {
+                let indices = face
+                    .adjacent_vertices()
+                    .zip(frames.iter())
+                    .map(|(vertex, frame)| builder.insert_vertex(f(face, vertex, *frame)))
+                    .collect::<Result<SmallVec<[_; 3]>, _>>()?;
+                builder.facets_with(|builder| {
+                    builder.insert_facet(indices.as_slice(), face.get().clone())
+                })?;
+            }
+            Ok(())
+        })?;
+        Ok(builder.build()?)
+    }
+
+    /// Creates a [`Buildable`] mesh data structure from the graph, splitting
+    /// vertices along creases.
+    ///
+    /// An edge is a _crease_ if the angle between the normals of its adjacent
+    /// faces exceeds the given angle (in radians). The faces adjacent to a
+    /// vertex are partitioned into groups that are connected by edges that are
+    /// not creases and a vertex is inserted into the output for each such
+    /// group. The given function is called with each vertex and the normal of
+    /// the group, which is computed from the normals of its faces using the
+    /// given weighting. The data of each face is inserted into the output via
+    /// [`FromGeometry`].
+    ///
+    /// This produces output that is smoothly shaded except at sharp features,
+    /// which lies between the output of [`MeshGraph::to_mesh_by_vertex_with`]
+    /// (no splitting) and [`MeshGraph::to_mesh_by_face_with`] (splitting
+    /// along every edge). Boundary edges always split vertices.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::buffer::MeshBuffer;
+    /// use plexus::geometry::Vector;
+    /// use plexus::graph::{MeshGraph, NormalWeight};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::BoundedPolygon;
+    /// use std::f64::consts::FRAC_PI_4;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// pub struct Vertex {
+    ///     pub position: E3,
+    ///     pub normal: Vector<E3>,
+    /// }
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    ///
+    /// // Every edge of a cube is a crease, so vertices are split for each face.
+    /// let buffer: MeshBuffer<BoundedPolygon<usize>, _> = graph
+    ///     .to_mesh_by_crease_with(FRAC_PI_4, NormalWeight::Angle, |vertex, normal| Vertex {
+    ///         position: *vertex.position(),
+    ///         normal,
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(24, buffer.as_vertex_slice().len());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if normals cannot be computed, the vertex data cannot
+    /// be inserted into the output, there are arity conflicts, or the output
+    /// does not support topology found in the graph.
+    ///
+    /// [`Buildable`]: crate::builder::Buildable
+    /// [`FromGeometry`]: crate::geometry::FromGeometry
+    /// [`MeshGraph::to_mesh_by_face_with`]: crate::graph::MeshGraph::to_mesh_by_face_with
+    /// [`MeshGraph::to_mesh_by_vertex_with`]: crate::graph::MeshGraph::to_mesh_by_vertex_with
+    pub fn to_mesh_by_crease_with<B, T, F>(
+        &self,
+        angle: T,
+        weight: NormalWeight,
+        mut f: F,
+    ) -> Result<B, GraphError>
+    where
+        B: Buildable,
+        B::Facet: FromGeometry<G::Face>,
+        GraphError: From<B::Error>,
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: VertexNormal,
+        G::Vertex: AsPosition,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+        VertexPosition<G>: EuclideanSpace,
+        F: FnMut(VertexView<&Self>, Vector<VertexPosition<G>>) -> B::Vertex,
+    {
+        let angle = angle.into();
+        let normals = self
+            .faces()
+            .map(|face| face.normal().map(|normal| (face.key(), normal)))
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        // Partition the faces adjacent to each vertex into groups that are
+        // connected by non-crease edges. Each group is an output vertex.
+        let mut corners = HashMap::with_capacity(self.arc_count());
+        let mut groups = Vec::with_capacity(self.vertex_count());
+        for vertex in self.vertices() {
+            let arcs = vertex
+                .incoming_arcs()
+                .filter(|arc| !arc.is_boundary_arc())
+                .collect::<SmallVec<[_; 8]>>();
+            let faces = arcs
+                .iter()
+                .map(|arc| arc.face().expect_consistent().key())
+                .collect::<SmallVec<[_; 8]>>();
+            let mut roots = (0..faces.len()).collect::<SmallVec<[_; 8]>>();
+            let find = |roots: &SmallVec<[usize; 8]>, mut index: usize| {
+                while roots[index] != index {
+                    index = roots[index];
+                }
+                index
+            };
+            for (index, arc) in arcs.iter().enumerate() {
+                if let Some(face) = arc.opposite_arc().face() {
+                    let (a, b) = (normals[&faces[index]], normals[&face.key()]);
+                    if geometry::angle::<VertexPosition<G>>(a, b) > angle {
+                        continue;
+                    }
+                    if let Some(adjacent) = faces.iter().position(|key| *key == face.key()) {
+                        let (a, b) = (find(&roots, index), find(&roots, adjacent));
+                        roots[a] = b;
+                    }
+                }
+            }
+            let mut group = HashMap::<usize, usize>::with_capacity(faces.len());
+            let mut sums = SmallVec::<[Vector<VertexPosition<G>>; 8]>::new();
+            for (index, arc) in arcs.iter().enumerate() {
+                let root = find(&roots, index);
+                let normal = geometry::corner_normal(*arc, weight)?;
+                let n = *group.entry(root).or_insert_with(|| {
+                    sums.push(Zero::zero());
+                    sums.len() - 1
+                });
+                sums[n] = sums[n] + normal;
+                corners.insert((vertex.key(), faces[index]), groups.len() + n);
+            }
+            for sum in sums {
+                groups.push((vertex.key(), sum.normalize().ok_or(GraphError::Geometry)?));
+            }
+        }
+        let mut builder = B::builder();
+        builder.surface_with(|builder| {
+            let keys = groups
+                .iter()
+                .map(|(key, normal)| builder.insert_vertex(f(self.vertex(*key).unwrap(), *normal)))
+                .collect::<Result<Vec<_>, _>>()?;
+            builder.facets_with(|builder| {
+                for face in self.faces() {
+                    let indices = face
+                        .adjacent_vertices()
+                        .map(|vertex| keys[corners[&(vertex.key(), face.key())]])
+                        .collect::<SmallVec<[_; 8]>>();
+                    builder.insert_facet(indices.as_slice(), face.get().clone())?;
+                }
+                Ok(())
+            })
+        })?;
+        Ok(builder.build()?)
+    }
+}
+
+fn into_scalar<T>(value: f64) -> Result<T, GraphError>
+where
+    T: NumCast,
+{
+    <T as NumCast>::from(value).ok_or(GraphError::Geometry)
 }
 
 impl<G> AsStorage<Vertex<G>> for MeshGraph<G>
@@ -1706,9 +2852,11 @@ mod tests {
     use nalgebra::{Point2, Point3, Vector3};
     use num::Zero;
 
-    use crate::buffer::MeshBuffer3;
-    use crate::graph::{GraphData, GraphError, MeshGraph};
+    use crate::buffer::{MeshBuffer3, MeshBuffer4};
+    use crate::graph::{GraphData, GraphError, MeshGraph, NormalWeight};
+    use crate::index;
     use crate::prelude::*;
+    use crate::primitive::cube::Cube;
     use crate::primitive::generate::Position;
     use crate::primitive::sphere::UvSphere;
     use crate::primitive::NGon;
@@ -1727,6 +2875,130 @@ mod tests {
         assert_eq!(6, graph.face_count());
     }
 
+    #[test]
+    fn crease() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new()
+            .polygons::<Position<E3>>() // 6 quadrilaterals, 24 vertices.
+            .collect();
+
+        // Every edge of a cube is a crease given a threshold less than a right
+        // angle, so vertices are split for each face.
+        let buffer: MeshBuffer4<usize, _> = graph
+            .to_mesh_by_crease_with(1.0, NormalWeight::Angle, |vertex, _| *vertex.position())
+            .unwrap();
+        assert_eq!(24, buffer.as_vertex_slice().len());
+
+        // No edges are creases given a threshold greater than a right angle.
+        let buffer: MeshBuffer4<usize, _> = graph
+            .to_mesh_by_crease_with(2.0, NormalWeight::Angle, |vertex, _| *vertex.position())
+            .unwrap();
+        assert_eq!(8, buffer.as_vertex_slice().len());
+    }
+
+    #[test]
+    fn self_intersections() {
+        let mut graph: MeshGraph<Point3<f64>> = UvSphere::new(16, 8)
+            .polygons::<Position<E3>>() // 128 polygons.
+            .collect();
+        assert!(graph.self_intersections().is_empty());
+
+        // Push a vertex through the opposite side of the sphere.
+        let key = graph
+            .vertices()
+            .find(|vertex| vertex.position().z > 0.99)
+            .unwrap()
+            .key();
+        *graph.vertex_mut(key).unwrap().get_mut() = Point3::new(0.0, 0.0, -2.0);
+        assert!(!graph.self_intersections().is_empty());
+    }
+
+    #[test]
+    fn signed_distance() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new()
+            .polygons::<Position<E3>>() // 6 quadrilaterals, 24 vertices.
+            .collect();
+        let bvh = graph.bvh();
+        let distance = |x, y, z| graph.signed_distance_with(&bvh, Point3::new(x, y, z)).unwrap();
+
+        // Points nearest to faces.
+        assert!((distance(0.0, 0.0, 0.0) + 0.5).abs() < 1e-9);
+        assert!((distance(0.25, 0.0, 0.0) + 0.25).abs() < 1e-9);
+        assert!((distance(1.0, 0.0, 0.0) - 0.5).abs() < 1e-9);
+        // Points nearest to edges and vertices.
+        assert!((distance(1.0, 1.0, 0.0) - 0.5f64.sqrt()).abs() < 1e-9);
+        assert!((distance(1.0, 1.0, 1.0) - 0.75f64.sqrt()).abs() < 1e-9);
+
+        let closest = graph.closest_point(Point3::new(0.0, 0.0, 2.0)).unwrap();
+        assert!((closest.point - Point3::new(0.0, 0.0, 0.5)).norm() < 1e-9);
+    }
+
+    #[test]
+    fn deviation() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new()
+            .polygons::<Position<E3>>() // 6 quadrilaterals, 24 vertices.
+            .collect();
+        let deviation = graph.deviation_from(&graph, 1024, 0).unwrap();
+        assert!(deviation.hausdorff.abs() < 1e-9);
+        assert!(deviation.rms.abs() < 1e-9);
+
+        let mut other: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        for mut vertex in other.vertex_orphans() {
+            *vertex.get_mut() += Vector3::x() * 0.1;
+        }
+        let deviation = graph.symmetric_deviation(&other, 1024, 0).unwrap();
+        assert!((deviation.hausdorff - 0.1).abs() < 1e-9);
+        assert!(deviation.mean > 0.0);
+        assert!(deviation.mean < 0.1);
+        assert!(deviation.mean <= deviation.rms);
+    }
+
+    #[test]
+    fn integral_properties() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new()
+            .polygons::<Position<E3>>() // 6 quadrilaterals, 24 vertices.
+            .collect();
+
+        // The cube has unit width and is centered at the origin.
+        assert!((graph.area() - 6.0).abs() < 1e-9);
+        assert!((graph.volume().unwrap() - 1.0).abs() < 1e-9);
+        assert!((graph.center_of_mass().unwrap() - Point3::origin()).norm() < 1e-9);
+        let tensor = graph.inertia_tensor().unwrap();
+        for (i, row) in tensor.iter().enumerate() {
+            for (j, entry) in row.iter().enumerate() {
+                let expected = if i == j { 1.0 / 6.0 } else { 0.0 };
+                assert!((entry - expected).abs() < 1e-9);
+            }
+        }
+
+        // Stretch the cube into a 2x1x3 box and translate it away from the
+        // origin. The center of mass moves with the box and the tensor is
+        // computed about the center of mass rather than the origin.
+        let mut graph = graph;
+        for mut vertex in graph.vertex_orphans() {
+            let position = vertex.get_mut();
+            *position = Point3::new(
+                (position.x * 2.0) + 1.0,
+                position.y - 2.0,
+                (position.z * 3.0) + 0.5,
+            );
+        }
+        assert!((graph.area() - 22.0).abs() < 1e-9);
+        assert!((graph.volume().unwrap() - 6.0).abs() < 1e-9);
+        assert!((graph.center_of_mass().unwrap() - Point3::new(1.0, -2.0, 0.5)).norm() < 1e-9);
+        let tensor = graph.inertia_tensor().unwrap();
+        for (i, row) in tensor.iter().enumerate() {
+            for (j, entry) in row.iter().enumerate() {
+                let expected = if i == j { [5.0, 6.5, 2.5][i] } else { 0.0 };
+                assert!((entry - expected).abs() < 1e-9);
+            }
+        }
+
+        // Volume is undefined for graphs that are not closed.
+        let key = graph.faces().next().unwrap().key();
+        graph.face_mut(key).unwrap().remove();
+        assert!(matches!(graph.volume(), Err(GraphError::TopologyMalformed)));
+    }
+
     #[test]
     fn iterate() {
         let mut graph: MeshGraph<Point3<f64>> = UvSphere::new(4, 2)
@@ -1801,6 +3073,21 @@ mod tests {
         assert_eq!(graph.err().unwrap(), GraphError::TopologyConflict);
     }
 
+    #[test]
+    fn non_manifold_edges() {
+        // Use the same "fan" as `error_on_non_manifold` and an additional
+        // triangle with inconsistent winding.
+        let edges = index::non_manifold_edges(vec![
+            NGon([0u32, 1, 2]),
+            NGon([0, 1, 3]),
+            NGon([0, 1, 4]),
+            NGon([2, 4, 5]),
+            NGon([2, 4, 6]),
+        ]);
+
+        assert_eq!(vec![NGon([0, 1]), NGon([2, 4])], edges);
+    }
+
     // This test is a sanity check for circulators over orphan views and the
     // unsafe transmutations used to coerce lifetimes. It is a good target for
     // Miri, which can detect certain memory safety issues.
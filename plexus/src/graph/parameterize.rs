@@ -0,0 +1,694 @@
+//! Surface parameterization.
+//!
+//! This module computes two-dimensional coordinates (typically texture
+//! coordinates) for the vertices of a graph that is topologically equivalent to
+//! a disk. Such a graph is connected and has exactly one boundary. Closed
+//! surfaces must be cut along seams before they can be parameterized.
+//!
+//! Computations are performed using `f64` regardless of the scalar type of
+//! vertex positions and the resulting coordinates are normalized into the unit
+//! square.
+
+use num::ToPrimitive;
+use smallvec::SmallVec;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use theon::space::{EuclideanSpace, FiniteDimensional};
+use theon::AsPosition;
+use typenum::U3;
+
+use crate::entity::view::ClosedView;
+use crate::graph::geometry::VertexPosition;
+use crate::graph::vertex::VertexKey;
+use crate::graph::{GraphData, GraphError, MeshGraph};
+
+const TOLERANCE: f64 = 1e-10;
+
+/// Shape of a fixed boundary.
+///
+/// Fixed boundary parameterizations map the boundary of a graph onto a convex
+/// shape within the unit square. Boundary vertices are distributed along the
+/// shape proportionally to the lengths of boundary edges.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Boundary {
+    /// Circle inscribed in the unit square.
+    Circle,
+    /// The unit square.
+    Square,
+}
+
+/// Surface parameterization method.
+///
+/// See [`MeshGraph::parameterize_vertices_with`].
+///
+/// [`MeshGraph::parameterize_vertices_with`]: crate::graph::MeshGraph::parameterize_vertices_with
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Parameterization {
+    /// Tutte embedding.
+    ///
+    /// The boundary is fixed to the given shape and each interior vertex is
+    /// placed at the mean of its adjacent vertices. Because the boundary is
+    /// convex, this never folds faces, but it ignores the geometry of the
+    /// surface and can exhibit severe distortion.
+    Tutte(Boundary),
+    /// Harmonic (discrete conformal) embedding.
+    ///
+    /// The boundary is fixed to the given shape and interior vertices are
+    /// placed by minimizing the Dirichlet energy using cotangent weights. This
+    /// better preserves angles than a Tutte embedding, but may fold faces when
+    /// triangles are obtuse.
+    Harmonic(Boundary),
+    /// Least squares conformal map (LSCM).
+    ///
+    /// The boundary is free and only two vertices are pinned. This minimizes
+    /// angle distortion and typically produces less distortion than fixed
+    /// boundary methods.
+    Conformal,
+}
+
+// Sparse matrix stored as a row-major list of non-zero entries.
+struct SparseMatrix {
+    columns: usize,
+    rows: Vec<Vec<(usize, f64)>>,
+}
+
+impl SparseMatrix {
+    fn new(columns: usize) -> Self {
+        SparseMatrix {
+            columns,
+            rows: Vec::new(),
+        }
+    }
+
+    fn push_row(&mut self, row: Vec<(usize, f64)>) {
+        self.rows.push(row);
+    }
+
+    fn mul(&self, x: &[f64]) -> Vec<f64> {
+        self.rows
+            .iter()
+            .map(|row| row.iter().map(|&(j, a)| a * x[j]).sum())
+            .collect()
+    }
+
+    fn mul_transpose(&self, y: &[f64]) -> Vec<f64> {
+        let mut x = vec![0.0; self.columns];
+        for (row, yi) in self.rows.iter().zip(y) {
+            for &(j, a) in row {
+                x[j] += a * yi;
+            }
+        }
+        x
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+// Solves `Ax = b` for a symmetric positive definite matrix `A` using the
+// conjugate gradient method. Returns `None` if the solver does not converge.
+fn solve_symmetric(matrix: &SparseMatrix, b: &[f64]) -> Option<Vec<f64>> {
+    let n = matrix.columns;
+    let mut x = vec![0.0; n];
+    let mut r = b.to_vec();
+    let mut p = r.clone();
+    let mut rr = dot(&r, &r);
+    let epsilon = TOLERANCE * dot(b, b).sqrt().max(1.0);
+    for _ in 0..(n * 10).max(1000) {
+        if rr.sqrt() <= epsilon {
+            return Some(x);
+        }
+        let ap = matrix.mul(&p);
+        let pap = dot(&p, &ap);
+        if pap.abs() <= f64::EPSILON {
+            return None;
+        }
+        let alpha = rr / pap;
+        for ((xi, ri), (pi, api)) in x.iter_mut().zip(r.iter_mut()).zip(p.iter().zip(ap)) {
+            *xi += alpha * pi;
+            *ri -= alpha * api;
+        }
+        let next = dot(&r, &r);
+        let beta = next / rr;
+        rr = next;
+        for (pi, ri) in p.iter_mut().zip(r.iter()) {
+            *pi = ri + (beta * *pi);
+        }
+    }
+    if rr.sqrt() <= epsilon {
+        Some(x)
+    }
+    else {
+        None
+    }
+}
+
+// Minimizes `|Ax - b|` using the conjugate gradient method on the normal
+// equations (CGLS). Returns `None` if the solver does not converge.
+fn solve_least_squares(matrix: &SparseMatrix, b: &[f64]) -> Option<Vec<f64>> {
+    let n = matrix.columns;
+    let mut x = vec![0.0; n];
+    let mut r = b.to_vec();
+    let mut s = matrix.mul_transpose(&r);
+    let mut p = s.clone();
+    let mut gamma = dot(&s, &s);
+    let epsilon = TOLERANCE * gamma.sqrt().max(1.0);
+    for _ in 0..(n * 10).max(1000) {
+        if gamma.sqrt() <= epsilon {
+            return Some(x);
+        }
+        let q = matrix.mul(&p);
+        let qq = dot(&q, &q);
+        if qq <= f64::EPSILON * f64::EPSILON {
+            return None;
+        }
+        let alpha = gamma / qq;
+        for (xi, pi) in x.iter_mut().zip(p.iter()) {
+            *xi += alpha * pi;
+        }
+        for (ri, qi) in r.iter_mut().zip(q.iter()) {
+            *ri -= alpha * qi;
+        }
+        s = matrix.mul_transpose(&r);
+        let next = dot(&s, &s);
+        let beta = next / gamma;
+        gamma = next;
+        for (pi, si) in p.iter_mut().zip(s.iter()) {
+            *pi = si + (beta * *pi);
+        }
+    }
+    if gamma.sqrt() <= epsilon {
+        Some(x)
+    }
+    else {
+        None
+    }
+}
+
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn magnitude(a: [f64; 3]) -> f64 {
+    dot(&a, &a).sqrt()
+}
+
+// Cotangent of the angle between two vectors.
+fn cotangent(a: [f64; 3], b: [f64; 3]) -> f64 {
+    let sine = magnitude(cross(a, b));
+    if sine <= f64::EPSILON {
+        0.0
+    }
+    else {
+        dot(&a, &b) / sine
+    }
+}
+
+// Topology and positions of a disk-like graph indexed by contiguous integers.
+struct Disk {
+    keys: Vec<VertexKey>,
+    positions: Vec<[f64; 3]>,
+    edges: Vec<(usize, usize)>,
+    // Fan triangulation of each face.
+    triangles: Vec<[usize; 3]>,
+    // Boundary vertices in counter-clockwise order with respect to faces.
+    boundary: Vec<usize>,
+}
+
+impl Disk {
+    fn from_graph<G>(graph: &MeshGraph<G>) -> Result<Self, GraphError>
+    where
+        G: GraphData,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        if graph.face_count() == 0 || graph.disjoint_subgraph_vertices().len() != 1 {
+            return Err(GraphError::TopologyMalformed);
+        }
+        // A disk has Euler characteristic one.
+        if graph.vertex_count() + graph.face_count() != graph.edge_count() + 1 {
+            return Err(GraphError::TopologyMalformed);
+        }
+        let mut keys = Vec::with_capacity(graph.vertex_count());
+        let mut positions = Vec::with_capacity(graph.vertex_count());
+        let mut indices = HashMap::with_capacity(graph.vertex_count());
+        for vertex in graph.vertices() {
+            let (x, y, z) = vertex.position().into_xyz();
+            match (x.to_f64(), y.to_f64(), z.to_f64()) {
+                (Some(x), Some(y), Some(z)) => positions.push([x, y, z]),
+                _ => return Err(GraphError::Geometry),
+            }
+            indices.insert(vertex.key(), keys.len());
+            keys.push(vertex.key());
+        }
+        let edges = graph
+            .edges()
+            .map(|edge| {
+                let (a, b): (VertexKey, VertexKey) = edge.arc().key().into();
+                (indices[&a], indices[&b])
+            })
+            .collect();
+        let mut triangles = Vec::with_capacity(graph.face_count());
+        for face in graph.faces() {
+            let ring = face
+                .adjacent_vertices()
+                .map(|vertex| indices[&vertex.key()])
+                .collect::<SmallVec<[_; 4]>>();
+            for n in 1..(ring.len() - 1) {
+                triangles.push([ring[0], ring[n], ring[n + 1]]);
+            }
+        }
+        let start = graph
+            .arcs()
+            .find(|arc| arc.is_boundary_arc())
+            .ok_or(GraphError::TopologyMalformed)?;
+        let mut boundary = Vec::new();
+        let mut arc = start;
+        loop {
+            boundary.push(indices[&arc.destination_vertex().key()]);
+            arc = arc.into_next_arc();
+            if arc.key() == start.key() {
+                break;
+            }
+        }
+        // Boundary arcs circulate opposite to the winding of faces.
+        boundary.reverse();
+        if graph.arcs().filter(|arc| arc.is_boundary_arc()).count() != boundary.len() {
+            return Err(GraphError::TopologyMalformed);
+        }
+        Ok(Disk {
+            keys,
+            positions,
+            edges,
+            triangles,
+            boundary,
+        })
+    }
+
+    fn distance(&self, a: usize, b: usize) -> f64 {
+        magnitude(sub(self.positions[a], self.positions[b]))
+    }
+
+    fn fixed_boundary(&self, shape: Boundary) -> Result<Vec<(usize, [f64; 2])>, GraphError> {
+        let n = self.boundary.len();
+        let lengths = (0..n)
+            .map(|i| self.distance(self.boundary[i], self.boundary[(i + 1) % n]))
+            .collect::<Vec<_>>();
+        let perimeter = lengths.iter().sum::<f64>();
+        if perimeter <= f64::EPSILON {
+            return Err(GraphError::Geometry);
+        }
+        let mut t = 0.0;
+        Ok(self
+            .boundary
+            .iter()
+            .zip(lengths)
+            .map(|(&index, length)| {
+                let uv = match shape {
+                    Boundary::Circle => {
+                        let angle = 2.0 * PI * t;
+                        [0.5 + 0.5 * angle.cos(), 0.5 + 0.5 * angle.sin()]
+                    }
+                    Boundary::Square => {
+                        let s = 4.0 * t;
+                        let side = s.floor();
+                        let offset = s - side;
+                        match side as usize {
+                            0 => [offset, 0.0],
+                            1 => [1.0, offset],
+                            2 => [1.0 - offset, 1.0],
+                            _ => [0.0, 1.0 - offset],
+                        }
+                    }
+                };
+                t += length / perimeter;
+                (index, uv)
+            })
+            .collect())
+    }
+
+    fn embed_with_weights(
+        &self,
+        shape: Boundary,
+        weights: HashMap<(usize, usize), f64>,
+    ) -> Result<Vec<[f64; 2]>, GraphError> {
+        let n = self.keys.len();
+        let mut coordinates = vec![[0.0; 2]; n];
+        let mut fixed = vec![false; n];
+        for (index, uv) in self.fixed_boundary(shape)? {
+            coordinates[index] = uv;
+            fixed[index] = true;
+        }
+        let mut unknowns = vec![None; n];
+        let mut count = 0;
+        for (index, unknown) in unknowns.iter_mut().enumerate() {
+            if !fixed[index] {
+                *unknown = Some(count);
+                count += 1;
+            }
+        }
+        let mut adjacency = vec![Vec::new(); n];
+        for (&(a, b), &w) in weights.iter() {
+            adjacency[a].push((b, w));
+            adjacency[b].push((a, w));
+        }
+        let mut matrix = SparseMatrix::new(count);
+        let mut bu = Vec::with_capacity(count);
+        let mut bv = Vec::with_capacity(count);
+        for index in (0..n).filter(|&index| !fixed[index]) {
+            let mut row = Vec::with_capacity(adjacency[index].len() + 1);
+            let mut diagonal = 0.0;
+            let (mut u, mut v) = (0.0, 0.0);
+            for &(adjacent, w) in adjacency[index].iter() {
+                diagonal += w;
+                match unknowns[adjacent] {
+                    Some(column) => row.push((column, -w)),
+                    None => {
+                        u += w * coordinates[adjacent][0];
+                        v += w * coordinates[adjacent][1];
+                    }
+                }
+            }
+            row.push((unknowns[index].unwrap(), diagonal));
+            matrix.push_row(row);
+            bu.push(u);
+            bv.push(v);
+        }
+        let u = solve_symmetric(&matrix, &bu).ok_or(GraphError::Geometry)?;
+        let v = solve_symmetric(&matrix, &bv).ok_or(GraphError::Geometry)?;
+        for (index, unknown) in unknowns.into_iter().enumerate() {
+            if let Some(unknown) = unknown {
+                coordinates[index] = [u[unknown], v[unknown]];
+            }
+        }
+        Ok(coordinates)
+    }
+
+    fn tutte(&self, shape: Boundary) -> Result<Vec<[f64; 2]>, GraphError> {
+        let weights = self.edges.iter().map(|&edge| (edge, 1.0)).collect();
+        self.embed_with_weights(shape, weights)
+    }
+
+    fn harmonic(&self, shape: Boundary) -> Result<Vec<[f64; 2]>, GraphError> {
+        let mut weights = HashMap::new();
+        for triangle in self.triangles.iter() {
+            for n in 0..3 {
+                let (a, b, c) = (triangle[n], triangle[(n + 1) % 3], triangle[(n + 2) % 3]);
+                let p = self.positions[c];
+                let w = 0.5 * cotangent(sub(self.positions[a], p), sub(self.positions[b], p));
+                *weights.entry((a.min(b), a.max(b))).or_insert(0.0) += w;
+            }
+        }
+        self.embed_with_weights(shape, weights)
+    }
+
+    fn conformal(&self) -> Result<Vec<[f64; 2]>, GraphError> {
+        let n = self.keys.len();
+        // Pin the first boundary vertex and the boundary vertex farthest from
+        // it.
+        let a = self.boundary[0];
+        let (b, distance) = self
+            .boundary
+            .iter()
+            .map(|&b| (b, self.distance(a, b)))
+            .fold((a, 0.0), |max, next| if next.1 > max.1 { next } else { max });
+        if distance <= f64::EPSILON {
+            return Err(GraphError::Geometry);
+        }
+        let mut pinned = HashMap::with_capacity(2);
+        pinned.insert(a, [0.0, 0.0]);
+        pinned.insert(b, [distance, 0.0]);
+        // Each vertex has two unknowns: `u` and `v`.
+        let mut unknowns = vec![None; 2 * n];
+        let mut count = 0;
+        for index in (0..n).filter(|index| !pinned.contains_key(index)) {
+            unknowns[2 * index] = Some(count);
+            unknowns[(2 * index) + 1] = Some(count + 1);
+            count += 2;
+        }
+        let mut matrix = SparseMatrix::new(count);
+        let mut rhs = Vec::with_capacity(2 * self.triangles.len());
+        for triangle in self.triangles.iter() {
+            // Project the triangle into an orthonormal basis in its plane.
+            let [p0, p1, p2] = triangle.map(|index| self.positions[index]);
+            let (e1, e2) = (sub(p1, p0), sub(p2, p0));
+            let area = 0.5 * magnitude(cross(e1, e2));
+            let length = magnitude(e1);
+            if area <= f64::EPSILON || length <= f64::EPSILON {
+                continue;
+            }
+            let x = [0.0, length, dot(&e1, &e2) / length];
+            let y = [0.0, 0.0, (2.0 * area) / length];
+            let scale = (2.0 * area).sqrt();
+            let mut real = Vec::with_capacity(6);
+            let mut imaginary = Vec::with_capacity(6);
+            let (mut b_real, mut b_imaginary) = (0.0, 0.0);
+            for j in 0..3 {
+                let (k, l) = ((j + 1) % 3, (j + 2) % 3);
+                // Complex coefficient `W = (x_l - x_k) + i(y_l - y_k)`.
+                let wr = (x[l] - x[k]) / scale;
+                let wi = (y[l] - y[k]) / scale;
+                let index = triangle[j];
+                if let Some(&[u, v]) = pinned.get(&index) {
+                    b_real -= (wr * u) - (wi * v);
+                    b_imaginary -= (wi * u) + (wr * v);
+                }
+                else {
+                    let (u, v) = (
+                        unknowns[2 * index].unwrap(),
+                        unknowns[(2 * index) + 1].unwrap(),
+                    );
+                    real.push((u, wr));
+                    real.push((v, -wi));
+                    imaginary.push((u, wi));
+                    imaginary.push((v, wr));
+                }
+            }
+            matrix.push_row(real);
+            matrix.push_row(imaginary);
+            rhs.push(b_real);
+            rhs.push(b_imaginary);
+        }
+        let solution = solve_least_squares(&matrix, &rhs).ok_or(GraphError::Geometry)?;
+        Ok((0..n)
+            .map(|index| match pinned.get(&index) {
+                Some(&uv) => uv,
+                None => [
+                    solution[unknowns[2 * index].unwrap()],
+                    solution[unknowns[(2 * index) + 1].unwrap()],
+                ],
+            })
+            .collect())
+    }
+}
+
+// Translates and uniformly scales coordinates into the unit square.
+fn normalize(coordinates: &mut [[f64; 2]]) {
+    let mut min = [f64::INFINITY; 2];
+    let mut max = [f64::NEG_INFINITY; 2];
+    for uv in coordinates.iter() {
+        min = [min[0].min(uv[0]), min[1].min(uv[1])];
+        max = [max[0].max(uv[0]), max[1].max(uv[1])];
+    }
+    let extent = (max[0] - min[0]).max(max[1] - min[1]);
+    if extent > f64::EPSILON {
+        for uv in coordinates.iter_mut() {
+            *uv = [(uv[0] - min[0]) / extent, (uv[1] - min[1]) / extent];
+        }
+    }
+}
+
+pub(in crate::graph) fn parameterize<G>(
+    graph: &MeshGraph<G>,
+    parameterization: Parameterization,
+) -> Result<HashMap<VertexKey, [f64; 2]>, GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let disk = Disk::from_graph(graph)?;
+    let coordinates = match parameterization {
+        Parameterization::Tutte(shape) => disk.tutte(shape)?,
+        Parameterization::Harmonic(shape) => disk.harmonic(shape)?,
+        Parameterization::Conformal => {
+            let mut coordinates = disk.conformal()?;
+            normalize(&mut coordinates);
+            coordinates
+        }
+    };
+    Ok(disk.keys.into_iter().zip(coordinates).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+    use std::collections::HashMap;
+
+    use crate::graph::{Boundary, GraphError, MeshGraph, Parameterization};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::{Tetragon, Trigon};
+
+    type E3 = Point3<f64>;
+
+    // Creates a planar 2x2 grid of quadrilaterals with unit spacing.
+    fn grid() -> MeshGraph<E3> {
+        MeshGraph::<E3>::from_raw_buffers(
+            vec![
+                Tetragon::new(0usize, 1, 4, 3),
+                Tetragon::new(1, 2, 5, 4),
+                Tetragon::new(3, 4, 7, 6),
+                Tetragon::new(4, 5, 8, 7),
+            ],
+            (0..9).map(|index| ((index % 3) as f64, (index / 3) as f64, 0.0)),
+        )
+        .unwrap()
+    }
+
+    fn parameterize(
+        graph: &mut MeshGraph<E3>,
+        parameterization: Parameterization,
+    ) -> Vec<(E3, (f64, f64))> {
+        let mut uvs = HashMap::new();
+        graph
+            .parameterize_vertices_with(parameterization, |vertex, uv| {
+                uvs.insert(vertex.key(), uv);
+            })
+            .unwrap();
+        graph
+            .vertices()
+            .map(|vertex| (*vertex.position(), uvs[&vertex.key()]))
+            .collect()
+    }
+
+    #[test]
+    fn tutte_circle() {
+        let mut graph = grid();
+        let uvs = parameterize(&mut graph, Parameterization::Tutte(Boundary::Circle));
+
+        for (position, (u, v)) in uvs {
+            let radius = ((u - 0.5).powi(2) + (v - 0.5).powi(2)).sqrt();
+            if position == Point3::new(1.0, 1.0, 0.0) {
+                // The interior vertex is placed at the center of the circle.
+                assert!(radius < 1e-6);
+            }
+            else {
+                assert!((radius - 0.5).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn harmonic_circle() {
+        // Triangulate a planar 2x2 grid about an interior vertex that is offset
+        // from its center, so that cotangent weights differ from uniform
+        // weights.
+        let xy = |index: usize| {
+            if index == 4 {
+                (0.8, 0.7)
+            }
+            else {
+                ((index % 3) as f64, (index / 3) as f64)
+            }
+        };
+        let triangles = [
+            [0usize, 1, 4],
+            [1, 2, 4],
+            [2, 5, 4],
+            [5, 8, 4],
+            [8, 7, 4],
+            [7, 6, 4],
+            [6, 3, 4],
+            [3, 0, 4],
+        ];
+        let mut graph = MeshGraph::<E3>::from_raw_buffers(
+            triangles.iter().map(|&[a, b, c]| Trigon::new(a, b, c)),
+            (0..9).map(|index| {
+                let (x, y) = xy(index);
+                (x, y, 0.0)
+            }),
+        )
+        .unwrap();
+        let uvs = parameterize(&mut graph, Parameterization::Harmonic(Boundary::Circle))
+            .into_iter()
+            .map(|(position, uv)| ((position.x, position.y), uv))
+            .collect::<Vec<_>>();
+        let uv = |index: usize| {
+            let (x, y) = xy(index);
+            uvs.iter()
+                .find(|((px, py), _)| (px - x).abs() < 1e-9 && (py - y).abs() < 1e-9)
+                .unwrap()
+                .1
+        };
+
+        for index in (0..9).filter(|index| *index != 4) {
+            let (u, v) = uv(index);
+            let radius = ((u - 0.5).powi(2) + (v - 0.5).powi(2)).sqrt();
+            assert!((radius - 0.5).abs() < 1e-6);
+        }
+        // The interior vertex is the cotangent weighted mean of its adjacent
+        // vertices, which is not the center of the circle.
+        let cotangent = |a: (f64, f64), b: (f64, f64), c: (f64, f64)| {
+            let (u, v) = ((a.0 - c.0, a.1 - c.1), (b.0 - c.0, b.1 - c.1));
+            ((u.0 * v.0) + (u.1 * v.1)) / ((u.0 * v.1) - (u.1 * v.0)).abs()
+        };
+        let (mut u, mut v, mut total) = (0.0, 0.0, 0.0);
+        for triangle in triangles.iter() {
+            for n in 0..2 {
+                // Each triangle contributes to the weights of the two edges that
+                // are incident to the interior vertex.
+                let (a, c) = (triangle[n], triangle[1 - n]);
+                let w = 0.5 * cotangent(xy(a), xy(4), xy(c));
+                let (ua, va) = uv(a);
+                u += w * ua;
+                v += w * va;
+                total += w;
+            }
+        }
+        let (uc, vc) = uv(4);
+        assert!((uc - (u / total)).abs() < 1e-6);
+        assert!((vc - (v / total)).abs() < 1e-6);
+        assert!(((uc - 0.5).powi(2) + (vc - 0.5).powi(2)).sqrt() > 0.1);
+    }
+
+    #[test]
+    fn conformal_planar_similarity() {
+        let mut graph = grid();
+        let uvs = parameterize(&mut graph, Parameterization::Conformal);
+
+        // The parameterization of a planar graph is a similarity transform, so
+        // the ratio of distances is constant.
+        let ratio = |a: usize, b: usize| {
+            let ((p, (ua, va)), (q, (ub, vb))) = (uvs[a], uvs[b]);
+            ((ua - ub).powi(2) + (va - vb).powi(2)).sqrt() / (p - q).norm()
+        };
+        let expected = ratio(0, 1);
+        for a in 0..uvs.len() {
+            for b in (a + 1)..uvs.len() {
+                assert!((ratio(a, b) - expected).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn reject_closed_graph() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+
+        assert_eq!(
+            Err(GraphError::TopologyMalformed),
+            graph.parameterize_vertices_with(Parameterization::Conformal, |_, _| {}),
+        );
+    }
+}
@@ -0,0 +1,264 @@
+//! Surface sampling.
+//!
+//! This module draws points from the faces of a graph, either uniformly by
+//! area or with Poisson disk spacing. Samples are drawn from a small
+//! pseudorandom generator that is seeded by the caller, so the same graph and
+//! seed always produce the same samples.
+//!
+//! See [`MeshGraph::sample_uniform`] and [`MeshGraph::sample_poisson_disk`].
+//!
+//! [`MeshGraph::sample_poisson_disk`]: crate::graph::MeshGraph::sample_poisson_disk
+//! [`MeshGraph::sample_uniform`]: crate::graph::MeshGraph::sample_uniform
+
+use num::ToPrimitive;
+use std::collections::HashMap;
+use theon::ops::Cross;
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use typenum::U3;
+
+use crate::graph::face::FaceKey;
+use crate::graph::vertex::VertexKey;
+use crate::graph::{into_scalar, GraphError};
+
+// The number of candidate samples per squared radius of area that are drawn
+// for Poisson disk sampling.
+const CANDIDATE_DENSITY: f64 = 16.0;
+// The maximum number of candidate samples that are drawn for Poisson disk
+// sampling. This bounds the memory used when the radius is small relative to
+// the area of a graph.
+const MAX_CANDIDATE_COUNT: usize = 1 << 24;
+
+pub(in crate::graph) type Triangle<S> = ((FaceKey, [VertexKey; 3]), [S; 3]);
+
+/// Point sampled from the surface of a [`MeshGraph`].
+///
+/// Faces are decomposed into triangles about their leading vertex. The
+/// barycentric coordinates of a sample are relative to the vertices of the
+/// triangle from which it is drawn, and can be used to interpolate vertex
+/// data.
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+#[derive(Clone, Copy)]
+pub struct Sample<S>
+where
+    S: EuclideanSpace,
+{
+    /// Key of the face from which the point is sampled.
+    pub face: FaceKey,
+    /// Keys of the vertices of the triangle from which the point is sampled.
+    pub vertices: [VertexKey; 3],
+    /// Barycentric coordinates of the point with respect to `vertices`.
+    pub barycentric: (Scalar<S>, Scalar<S>, Scalar<S>),
+    pub point: S,
+}
+
+// SplitMix64. This generator is small and its output is fully determined by
+// its seed on all platforms, so samples are reproducible.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // Gets a number in the interval `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+pub(in crate::graph) fn uniform<S>(
+    triangles: &[Triangle<S>],
+    count: usize,
+    seed: u64,
+) -> Result<Vec<Sample<S>>, GraphError>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    let mut total = 0.0;
+    let cumulative = triangles
+        .iter()
+        .map(|(_, [a, b, c])| {
+            total += (*b - *a)
+                .cross(*c - *a)
+                .magnitude()
+                .to_f64()
+                .ok_or(GraphError::Geometry)?;
+            Ok(total)
+        })
+        .collect::<Result<Vec<_>, GraphError>>()?;
+    if !(total > 0.0) {
+        return Err(GraphError::Geometry);
+    }
+    let mut rng = Rng::new(seed);
+    (0..count)
+        .map(|_| {
+            let x = rng.next_f64() * total;
+            let index = cumulative
+                .partition_point(|area| *area <= x)
+                .min(triangles.len() - 1);
+            sample(&triangles[index], rng.next_f64(), rng.next_f64())
+        })
+        .collect()
+}
+
+pub(in crate::graph) fn poisson_disk<S>(
+    triangles: &[Triangle<S>],
+    radius: Scalar<S>,
+    seed: u64,
+) -> Result<Vec<Sample<S>>, GraphError>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    let radius = radius.to_f64().ok_or(GraphError::Geometry)?;
+    if !(radius > 0.0) {
+        return Err(GraphError::Geometry);
+    }
+    let area = triangles.iter().try_fold(0.0, |sum, (_, [a, b, c])| {
+        (*b - *a)
+            .cross(*c - *a)
+            .magnitude()
+            .to_f64()
+            .map(|area| sum + (area / 2.0))
+            .ok_or(GraphError::Geometry)
+    })?;
+    let count = ((area / (radius * radius)) * CANDIDATE_DENSITY).ceil();
+    // This also rejects radii that are so small that the count is not finite.
+    if !(count <= MAX_CANDIDATE_COUNT as f64) {
+        return Err(GraphError::Geometry);
+    }
+    let count = count as usize;
+    // Candidates are drawn uniformly and accepted in order if they are not
+    // within the radius of an accepted sample. Accepted samples are binned
+    // into a grid with cells as wide as the radius, so only neighboring cells
+    // need to be searched.
+    let mut cells = HashMap::<[i64; 3], Vec<[f64; 3]>>::new();
+    let mut samples = Vec::new();
+    for candidate in uniform(triangles, count, seed)? {
+        let (x, y, z) = candidate.point.into_xyz();
+        let point = [
+            x.to_f64().ok_or(GraphError::Geometry)?,
+            y.to_f64().ok_or(GraphError::Geometry)?,
+            z.to_f64().ok_or(GraphError::Geometry)?,
+        ];
+        let cell = [
+            (point[0] / radius).floor() as i64,
+            (point[1] / radius).floor() as i64,
+            (point[2] / radius).floor() as i64,
+        ];
+        let is_near = (-1..=1).any(|i| {
+            (-1..=1).any(|j| {
+                (-1..=1).any(|k| {
+                    cells
+                        .get(&[cell[0] + i, cell[1] + j, cell[2] + k])
+                        .map_or(false, |points| {
+                            points.iter().any(|other| {
+                                let distance = point
+                                    .iter()
+                                    .zip(other.iter())
+                                    .map(|(a, b)| (a - b) * (a - b))
+                                    .sum::<f64>();
+                                distance < radius * radius
+                            })
+                        })
+                })
+            })
+        });
+        if !is_near {
+            cells.entry(cell).or_insert_with(Vec::new).push(point);
+            samples.push(candidate);
+        }
+    }
+    Ok(samples)
+}
+
+fn sample<S>(triangle: &Triangle<S>, r1: f64, r2: f64) -> Result<Sample<S>, GraphError>
+where
+    S: EuclideanSpace,
+{
+    let ((face, vertices), [a, b, c]) = *triangle;
+    // Taking the square root of `r1` distributes points uniformly over the
+    // area of the triangle rather than toward its leading vertex.
+    let s = r1.sqrt();
+    let v = into_scalar::<Scalar<S>>(s * (1.0 - r2))?;
+    let w = into_scalar::<Scalar<S>>(s * r2)?;
+    Ok(Sample {
+        face,
+        vertices,
+        barycentric: (into_scalar::<Scalar<S>>(1.0 - s)?, v, w),
+        point: a + ((b - a) * v) + ((c - a) * w),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+    use theon::space::InnerSpace;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn sample_uniform() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+
+        let samples = graph.sample_uniform(500, 7).unwrap();
+        assert_eq!(500, samples.len());
+        for sample in samples.iter() {
+            let (u, v, w) = sample.barycentric;
+            assert!((1.0 - (u + v + w)).abs() < 1e-9);
+            assert!(u >= 0.0 && v >= 0.0 && w >= 0.0);
+            // Every point lies on the surface of the cube.
+            let extent = sample.point.coords.amax();
+            assert!((extent - 0.5).abs() < 1e-9);
+            assert!(graph.face(sample.face).is_some());
+        }
+
+        // Samples are determined by the seed.
+        let other = graph.sample_uniform(500, 7).unwrap();
+        assert!(samples
+            .iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.point == b.point && a.face == b.face));
+        let other = graph.sample_uniform(500, 8).unwrap();
+        assert!(samples
+            .iter()
+            .zip(other.iter())
+            .any(|(a, b)| a.point != b.point));
+    }
+
+    #[test]
+    fn sample_poisson_disk() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+
+        let radius = 0.1;
+        let samples = graph.sample_poisson_disk(radius, 3).unwrap();
+        assert!(samples.len() > 100);
+        for (n, a) in samples.iter().enumerate() {
+            for b in samples[(n + 1)..].iter() {
+                assert!((a.point - b.point).magnitude() >= radius);
+            }
+        }
+
+        assert!(graph.sample_poisson_disk(0.0, 3).is_err());
+        // Radii that are too small relative to the area of the graph are
+        // rejected rather than drawing an unbounded number of candidates.
+        assert!(graph.sample_poisson_disk(1e-6, 3).is_err());
+        assert!(graph.sample_poisson_disk(1e-300, 3).is_err());
+    }
+}
@@ -3,7 +3,7 @@ use smallvec::SmallVec;
 use std::borrow::Borrow;
 use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
-use theon::space::Vector;
+use theon::space::{Scalar, Vector};
 use theon::AsPosition;
 
 use crate::entity::borrow::{Reborrow, ReborrowInto, ReborrowMut};
@@ -19,7 +19,9 @@ use crate::geometry::Metric;
 use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::edge::{Arc, ArcKey, ArcOrphan, ArcView, Edge};
 use crate::graph::face::{Face, FaceOrphan, FaceView};
-use crate::graph::geometry::{VertexCentroid, VertexNormal, VertexPosition};
+use crate::graph::geometry::{
+    Curvature, NormalWeight, VertexCentroid, VertexCurvature, VertexNormal, VertexPosition,
+};
 use crate::graph::mutation::vertex::{self, VertexRemoveCache};
 use crate::graph::mutation::{self, Consistent, Immediate, Mutable};
 use crate::graph::path::Path;
@@ -309,6 +311,66 @@ where
     {
         <G as VertexNormal>::normal(self.to_ref())
     }
+
+    /// Gets the normal of the vertex using the given weighting of the normals
+    /// of its adjacent faces.
+    ///
+    /// [`VertexView::normal`] weights faces uniformly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the normal cannot be computed, such as when the
+    /// vertex has no adjacent faces or the weighted normals cancel.
+    pub fn weighted_normal(
+        &self,
+        weight: NormalWeight,
+    ) -> Result<Vector<VertexPosition<G>>, GraphError>
+    where
+        G: VertexNormal,
+        G::Vertex: AsPosition,
+    {
+        <G as VertexNormal>::weighted_normal(self.to_ref(), weight)
+    }
+
+    /// Gets the discrete curvature of the surface at the vertex.
+    ///
+    /// See [`VertexCurvature`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the curvature cannot be computed, such as when
+    /// adjacent faces are degenerate.
+    ///
+    /// [`VertexCurvature`]: crate::graph::VertexCurvature
+    pub fn curvature(&self) -> Result<Curvature<VertexPosition<G>>, GraphError>
+    where
+        G: VertexCurvature,
+        G::Vertex: AsPosition,
+    {
+        <G as VertexCurvature>::curvature(self.to_ref())
+    }
+
+    /// Gets the Gaussian curvature of the surface at the vertex.
+    ///
+    /// See [`VertexView::curvature`].
+    pub fn gaussian_curvature(&self) -> Result<Scalar<VertexPosition<G>>, GraphError>
+    where
+        G: VertexCurvature,
+        G::Vertex: AsPosition,
+    {
+        self.curvature().map(|curvature| curvature.gaussian)
+    }
+
+    /// Gets the mean curvature of the surface at the vertex.
+    ///
+    /// See [`VertexView::curvature`].
+    pub fn mean_curvature(&self) -> Result<Scalar<VertexPosition<G>>, GraphError>
+    where
+        G: VertexCurvature,
+        G::Vertex: AsPosition,
+    {
+        self.curvature().map(|curvature| curvature.mean)
+    }
 }
 
 /// Reachable API.
@@ -1183,7 +1245,7 @@ mod tests {
     use decorum::R64;
     use nalgebra::{Point2, Point3};
 
-    use crate::graph::{GraphError, MeshGraph};
+    use crate::graph::{GraphError, MeshGraph, NormalWeight};
     use crate::prelude::*;
     use crate::primitive::cube::Cube;
     use crate::primitive::generate::Position;
@@ -1265,4 +1327,70 @@ mod tests {
         let vertex = graph.vertices().nth(0).unwrap();
         assert_eq!(graph.vertex_count(), vertex.traverse_by_depth().count());
     }
+
+    #[test]
+    fn angle_weighted_normal() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new()
+            .polygons::<Position<E3>>() // 6 quadrilaterals, 24 vertices.
+            .collect();
+        graph.triangulate();
+
+        // Each face of the cube forms a right angle at each corner regardless
+        // of triangulation, so angle weighted normals point along diagonals.
+        for vertex in graph.vertices() {
+            let normal = vertex.weighted_normal(NormalWeight::Angle).unwrap();
+            for component in normal.iter() {
+                assert!((component.abs() - (1.0f64 / 3.0).sqrt()).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn sphere_curvature() {
+        let graph: MeshGraph<Point3<f64>> = UvSphere::new(64, 32)
+            .polygons::<Position<E3>>()
+            .triangulate()
+            .collect();
+
+        // Both the Gaussian and mean curvature of a unit sphere are one.
+        for vertex in graph
+            .vertices()
+            .filter(|vertex| vertex.position().z.abs() < 0.5)
+        {
+            let curvature = vertex.curvature().unwrap();
+            assert!((curvature.gaussian - 1.0).abs() < 0.1);
+            assert!((curvature.mean - 1.0).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn plane_boundary_curvature() {
+        // Create a flat 2x2 grid of quadrilaterals split into triangles.
+        let mut indices = vec![];
+        for j in 0..2usize {
+            for i in 0..2usize {
+                let (a, b) = ((j * 3) + i, (j * 3) + i + 1);
+                let (c, d) = (b + 3, a + 3);
+                indices.push(Trigon::new(a, b, c));
+                indices.push(Trigon::new(a, c, d));
+            }
+        }
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            indices,
+            (0..9).map(|n| Point3::new((n % 3) as f64, (n / 3) as f64, 0.0)),
+        )
+        .unwrap();
+
+        // Neither the interior vertex nor the boundary vertices along the
+        // sides of the grid are curved, regardless of where their boundary
+        // arcs fall in their circulation.
+        for vertex in graph.vertices().filter(|vertex| {
+            let position = vertex.position();
+            position.x == 1.0 || position.y == 1.0
+        }) {
+            let curvature = vertex.curvature().unwrap();
+            assert!(curvature.gaussian.abs() < 1e-9);
+            assert!(curvature.mean.abs() < 1e-9);
+        }
+    }
 }
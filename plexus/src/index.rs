@@ -79,7 +79,7 @@
 
 use num::{Integer, NumCast, Unsigned};
 use std::cmp;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::marker::PhantomData;
@@ -88,7 +88,7 @@ use typenum::NonZero;
 
 use crate::constant::{Constant, ToType, TypeOf};
 use crate::primitive::decompose::IntoVertices;
-use crate::primitive::Topological;
+use crate::primitive::{Edge, Topological};
 use crate::{Monomorphic, StaticArity};
 
 pub(crate) type BufferOf<R> = Vec<<R as Grouping>::Group>;
@@ -735,3 +735,58 @@ where
         T::from_indexer(self, indexer)
     }
 }
+
+/// Gets the non-manifold edges of an index buffer.
+///
+/// An edge is non-manifold if it is shared by more than two polygons or by two
+/// polygons that traverse it in the same direction (i.e., polygons with
+/// inconsistent winding). Such edges cannot be represented by a
+/// [`MeshGraph`], so this can be used to find the edges that prevent the
+/// construction of a graph from raw buffers. Each edge is reported once in
+/// the order and direction in which it first appears.
+///
+/// # Examples
+///
+/// Finding an edge shared by three triangles:
+///
+/// ```rust
+/// use plexus::index;
+/// use plexus::primitive::{Edge, Trigon};
+///
+/// let edges = index::non_manifold_edges(vec![
+///     Trigon::new(0usize, 1, 2),
+///     Trigon::new(1, 0, 3),
+///     Trigon::new(0, 1, 4),
+/// ]);
+/// assert_eq!(vec![Edge::new(0, 1)], edges);
+/// ```
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+pub fn non_manifold_edges<P, I>(indices: I) -> Vec<Edge<P::Vertex>>
+where
+    I: IntoIterator<Item = P>,
+    P: Topological,
+    P::Vertex: Copy + Eq + Hash,
+{
+    let mut arcs = Vec::new();
+    let mut counts = HashMap::<_, usize>::new();
+    for polygon in indices {
+        for edge in polygon.edges() {
+            let [a, b] = edge.into_array();
+            let arc = (*a, *b);
+            arcs.push(arc);
+            *counts.entry(arc).or_default() += 1;
+        }
+    }
+    let mut edges = Vec::new();
+    let mut reported = HashSet::new();
+    for (a, b) in arcs {
+        let forward = counts[&(a, b)];
+        let backward = counts.get(&(b, a)).copied().unwrap_or(0);
+        if (forward > 1 || forward + backward > 2) && reported.insert((a, b)) {
+            reported.insert((b, a));
+            edges.push(Edge::new(a, b));
+        }
+    }
+    edges
+}
@@ -78,7 +78,8 @@ pub mod prelude {
     };
     pub use crate::primitive::generate::Generator as _;
     pub use crate::primitive::{
-        IntoPolygons as _, MapVertices as _, Polygonal as _, Topological as _,
+        IntoPolygons as _, MapVertices as _, Polygonal as _, PolygonalE2 as _, PolygonalE3 as _,
+        Topological as _,
     };
     pub use crate::DynamicArity as _;
     pub use crate::IteratorExt as _;
@@ -20,16 +20,18 @@
 //! ```
 
 use num::One;
+use std::cmp;
+use std::collections::HashMap;
 use theon::adjunct::{Converged, Map};
 use theon::query::Unit;
 use theon::space::{Basis, EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
-use typenum::U3;
+use typenum::{U2, U3};
 
 use crate::primitive::generate::{
     Attribute, AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
-    IndexingPolygonGenerator, Normal, PolygonGenerator, Position,
+    IndexingPolygonGenerator, Normal, PolygonGenerator, Position, UvMap,
 };
-use crate::primitive::Tetragon;
+use crate::primitive::{into_scalar, Tetragon};
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Plane {
@@ -62,6 +64,20 @@ impl Plane {
 
 impl Attribute for Plane {}
 
+/// Layout of texture coordinates on the faces of a cube.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum UvLayout {
+    /// Maps each face onto the entire unit square.
+    #[default]
+    Face,
+    /// Maps faces onto an unfolded cross in a $4 \times 3$ grid.
+    ///
+    /// The middle row contains the left, front, right, and back faces. The
+    /// top and bottom faces are above and below the front face, respectively.
+    /// Edges shared by adjacent faces in the cross are continuous.
+    Cross,
+}
+
 #[derive(Clone, Copy)]
 pub struct Bounds<S>
 where
@@ -285,4 +301,413 @@ impl IndexingPolygonGenerator<Plane> for Cube {
     }
 }
 
+impl<S> AttributeGenerator<UvMap<S>> for Cube
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type State = UvLayout;
+}
+
+impl<S> AttributeVertexGenerator<UvMap<S>> for Cube
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type Output = S;
+
+    // Texture coordinates are not shared between faces, so there are four for
+    // each face regardless of the layout.
+    fn vertex_count(&self) -> usize {
+        self.polygon_count() * 4
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        // Texture coordinates of the corners of each face, ordered as in the
+        // positional indexing polygons. Faces are viewed from the outside with
+        // `v` pointing up, except that `v` points toward the back on the top
+        // face and toward the front on the bottom face. This lines up the top
+        // and bottom faces with the front face in the `Cross` layout.
+        let (u, v) = match (index / 4, index % 4) {
+            (0, corner) | (4, corner) | (5, corner) => [(1, 0), (1, 1), (0, 1), (0, 0)][corner],
+            (1, corner) => [(1, 1), (0, 1), (0, 0), (1, 0)][corner],
+            (2, corner) | (3, corner) => [(0, 0), (1, 0), (1, 1), (0, 1)][corner],
+            _ => panic!(),
+        };
+        match *state {
+            UvLayout::Face => S::from_xy(into_scalar::<_, S>(u), into_scalar::<_, S>(v)),
+            UvLayout::Cross => {
+                let (column, row) = match index / 4 {
+                    0 => (1, 1), // front
+                    1 => (2, 1), // right
+                    2 => (1, 2), // top
+                    3 => (0, 1), // left
+                    4 => (1, 0), // bottom
+                    5 => (3, 1), // back
+                    _ => panic!(),
+                };
+                S::from_xy(
+                    into_scalar::<_, S>(column + u) / into_scalar::<_, S>(4),
+                    into_scalar::<_, S>(row + v) / into_scalar::<_, S>(3),
+                )
+            }
+        }
+    }
+}
+
+impl<S> AttributePolygonGenerator<UvMap<S>> for Cube
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type Output = Tetragon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<UvMap<S>>::indexing_polygon(self, index)
+            .map(|index| AttributeVertexGenerator::<UvMap<S>>::vertex_from(self, state, index))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<UvMap<S>> for Cube {
+    type Output = Tetragon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        assert!(index < self.polygon_count());
+        let offset = index * 4;
+        Tetragon::new(offset, offset + 1, offset + 2, offset + 3)
+    }
+}
+
 impl Generator for Cube {}
+
+/// Cube with subdivided faces.
+///
+/// Each face of a segmented cube is divided into a grid of quadrilaterals with
+/// `nx`, `ny`, and `nz` segments along the $x$, $y$, and $z$ axes,
+/// respectively. Positions are shared by adjacent faces and faces are ordered
+/// as in [`Cube`].
+///
+/// A segmented cube can be _spherified_, in which case its vertices are
+/// projected onto the sphere inscribed in its [`Bounds`]. This produces a
+/// _cube sphere_, which has no poles and less distortion near its corners than
+/// a simple normalization of positions.
+#[derive(Clone)]
+pub struct SegmentedCube {
+    // Lattice coordinates of positions on the surface.
+    lattice: Vec<[usize; 3]>,
+    // Face (plane) indices and positional indexing polygons.
+    polygons: Vec<(usize, Tetragon<usize>)>,
+    segments: [usize; 3],
+    is_spherified: bool,
+}
+
+impl SegmentedCube {
+    pub fn new(nx: usize, ny: usize, nz: usize) -> Self {
+        let [nx, ny, nz] = [cmp::max(1, nx), cmp::max(1, ny), cmp::max(1, nz)];
+        // Each face is described by the number of segments along its local
+        // axes $s$ and $t$ and a mapping from local grid coordinates to the
+        // lattice. The local axes are chosen such that $s \times t$ is the
+        // outward normal of the face.
+        let extents = [(nx, ny), (nz, ny), (nx, nz), (nz, ny), (nx, nz), (nx, ny)];
+        let point = |plane, s, t| match plane {
+            0 => [s, t, nz],      // front
+            1 => [nx, t, nz - s], // right
+            2 => [s, ny, nz - t], // top
+            3 => [0, t, s],       // left
+            4 => [s, 0, t],       // bottom
+            5 => [nx - s, t, 0],  // back
+            _ => panic!(),
+        };
+        let mut lattice = Vec::new();
+        let mut indices = HashMap::new();
+        let mut index = |plane, s, t| {
+            let point = point(plane, s, t);
+            *indices.entry(point).or_insert_with(|| {
+                lattice.push(point);
+                lattice.len() - 1
+            })
+        };
+        let mut polygons = Vec::new();
+        for (plane, &(ns, nt)) in extents.iter().enumerate() {
+            for t in 0..nt {
+                for s in 0..ns {
+                    polygons.push((
+                        plane,
+                        Tetragon::new(
+                            index(plane, s, t),
+                            index(plane, s + 1, t),
+                            index(plane, s + 1, t + 1),
+                            index(plane, s, t + 1),
+                        ),
+                    ));
+                }
+            }
+        }
+        SegmentedCube {
+            lattice,
+            polygons,
+            segments: [nx, ny, nz],
+            is_spherified: false,
+        }
+    }
+
+    pub fn with_spherify(mut self, is_spherified: bool) -> Self {
+        self.is_spherified = is_spherified;
+        self
+    }
+
+    // Gets the position of a vertex on the cube (or sphere) with radius one
+    // centered at the origin.
+    fn unit_position(&self, index: usize) -> [f64; 3] {
+        let point = self.lattice[index];
+        let [x, y, z] = [0, 1, 2].map(|axis| {
+            ((2.0 * point[axis] as f64) / (self.segments[axis] as f64)) - 1.0
+        });
+        if self.is_spherified {
+            let [xx, yy, zz] = [x * x, y * y, z * z];
+            [
+                x * (1.0 - (yy / 2.0) - (zz / 2.0) + (yy * zz / 3.0)).sqrt(),
+                y * (1.0 - (zz / 2.0) - (xx / 2.0) + (zz * xx / 3.0)).sqrt(),
+                z * (1.0 - (xx / 2.0) - (yy / 2.0) + (xx * yy / 3.0)).sqrt(),
+            ]
+        }
+        else {
+            [x, y, z]
+        }
+    }
+
+    fn vertex_with_position_from<S>(&self, state: &Bounds<S>, index: usize) -> S
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let two = Scalar::<S>::one() + One::one();
+        let center = (state.lower + state.upper) / two;
+        let radius = (state.upper - state.lower) / two;
+        let [x, y, z] = self.unit_position(index);
+        S::from_xyz(
+            center + (radius * into_scalar::<_, S>(x)),
+            center + (radius * into_scalar::<_, S>(y)),
+            center + (radius * into_scalar::<_, S>(z)),
+        )
+    }
+
+    fn vertex_with_normal_from<S>(&self, index: usize) -> Unit<Vector<S>>
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        if self.is_spherified {
+            let [x, y, z] = self.unit_position(index);
+            let normal = S::from_xyz(
+                into_scalar::<_, S>(x),
+                into_scalar::<_, S>(y),
+                into_scalar::<_, S>(z),
+            );
+            Unit::try_from_inner(normal.into_coordinates()).expect("non-zero vector")
+        }
+        else {
+            AttributeVertexGenerator::<Plane>::vertex_from(&Cube, &(), index).normal::<Vector<S>>()
+        }
+    }
+}
+
+impl Default for SegmentedCube {
+    fn default() -> Self {
+        SegmentedCube::new(4, 4, 4)
+    }
+}
+
+impl PolygonGenerator for SegmentedCube {
+    fn polygon_count(&self) -> usize {
+        self.polygons.len()
+    }
+}
+
+impl<S> AttributeGenerator<Normal<S>> for SegmentedCube
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<Normal<S>> for SegmentedCube
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Unit<Vector<S>>;
+
+    // Normals are smooth and shared like positions when spherified, but are
+    // otherwise the six normals of the faces.
+    fn vertex_count(&self) -> usize {
+        if self.is_spherified {
+            self.lattice.len()
+        }
+        else {
+            6
+        }
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        self.vertex_with_normal_from::<S>(index)
+    }
+}
+
+impl<S> AttributePolygonGenerator<Normal<S>> for SegmentedCube
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Tetragon<Unit<Vector<S>>>;
+
+    fn polygon_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Normal<S>>::indexing_polygon(self, index)
+            .map(|index| self.vertex_with_normal_from::<S>(index))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Normal<S>> for SegmentedCube {
+    type Output = Tetragon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        let (plane, polygon) = self.polygons[index];
+        if self.is_spherified {
+            polygon
+        }
+        else {
+            Tetragon::converged(plane)
+        }
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for SegmentedCube
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for SegmentedCube
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        self.lattice.len()
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        self.vertex_with_position_from(state, index)
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for SegmentedCube
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Tetragon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        self.polygons[index]
+            .1
+            .map(|index| self.vertex_with_position_from(state, index))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Position<S>> for SegmentedCube {
+    type Output = Tetragon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        self.polygons[index].1
+    }
+}
+
+impl Generator for SegmentedCube {}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Point2, Point3};
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::cube::{Bounds, Cube, SegmentedCube, UvLayout};
+    use crate::primitive::generate::{Normal, Position, UvMap};
+
+    type E2 = Point2<f64>;
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn uv_map_face_layout() {
+        let cube = Cube::new();
+        assert_eq!(24, cube.vertices::<UvMap<E2>>().count());
+        for polygon in cube.polygons::<UvMap<E2>>() {
+            let uvs = polygon.into_array();
+            // Every face covers the unit square with counter-clockwise winding.
+            let area = (0..4)
+                .map(|i| {
+                    let (a, b) = (uvs[i], uvs[(i + 1) % 4]);
+                    (a.x * b.y) - (b.x * a.y)
+                })
+                .sum::<f64>()
+                / 2.0;
+            assert!((area - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn uv_map_cross_layout() {
+        let cube = Cube::new();
+        let mut cells = cube
+            .polygons_from::<UvMap<E2>>(UvLayout::Cross)
+            .map(|polygon| {
+                let uvs = polygon.into_array();
+                let (u, v) = uvs.iter().fold((0.0, 0.0), |(u, v), uv| (u + uv.x, v + uv.y));
+                // Cells are a quarter wide and a third tall, so the mean of the
+                // corners is scaled by four and three, respectively.
+                (u as usize, (v * 3.0 / 4.0) as usize)
+            })
+            .collect::<Vec<_>>();
+        cells.sort_unstable();
+        assert_eq!(vec![(0, 1), (1, 0), (1, 1), (1, 2), (2, 1), (3, 1)], cells);
+    }
+
+    #[test]
+    fn segmented_vertex_and_polygon_count() {
+        let cube = SegmentedCube::new(1, 1, 1);
+        assert_eq!(8, cube.vertices::<Position<E3>>().count());
+        assert_eq!(6, cube.polygons::<Position<E3>>().count());
+
+        let cube = SegmentedCube::new(2, 3, 4);
+        // There are $(n_x + 1)(n_y + 1)(n_z + 1)$ lattice points, of which
+        // $(n_x - 1)(n_y - 1)(n_z - 1)$ are interior.
+        assert_eq!(54, cube.vertices::<Position<E3>>().count());
+        assert_eq!(52, cube.polygons::<Position<E3>>().count());
+        assert_eq!(6, cube.vertices::<Normal<E3>>().count());
+    }
+
+    #[test]
+    fn segmented_is_closed() {
+        let cube = SegmentedCube::new(2, 3, 4);
+        let graph = MeshGraph::<E3>::from_raw_buffers(
+            cube.indexing_polygons::<Position>(),
+            cube.vertices_from::<Position<E3>>(Bounds::with_width(2.0)),
+        )
+        .unwrap();
+        assert!((graph.volume().unwrap() - 8.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn segmented_spherify() {
+        let cube = SegmentedCube::new(8, 8, 8).with_spherify(true);
+        assert!(cube
+            .vertices_from::<Position<E3>>(Bounds::with_radius(2.0))
+            .all(|position| (position.coords.norm() - 2.0).abs() < 1e-9));
+        assert_eq!(
+            cube.vertices::<Position<E3>>().count(),
+            cube.vertices::<Normal<E3>>().count()
+        );
+
+        let graph = MeshGraph::<E3>::from_raw_buffers(
+            cube.indexing_polygons::<Position>(),
+            cube.vertices_from::<Position<E3>>(Bounds::with_radius(1.0)),
+        )
+        .unwrap();
+        let volume = graph.volume().unwrap();
+        assert!(volume > 4.0 && volume < (4.0 / 3.0) * std::f64::consts::PI);
+    }
+}
@@ -0,0 +1,792 @@
+//! Cylinder, cone, and capsule primitives.
+//!
+//! These primitives are surfaces of revolution about the $z$ axis and are
+//! centered at the origin. Each is formed from rings of vertices and, at their
+//! ends, poles on the axis.
+//!
+//! # Examples
+//!
+//! Generating a graph from the positional data of a capped cylinder:
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use nalgebra::Point3;
+//! use plexus::graph::MeshGraph;
+//! use plexus::prelude::*;
+//! use plexus::primitive::cylinder::{Bounds, Cylinder};
+//! use plexus::primitive::generate::Position;
+//!
+//! type E3 = Point3<f64>;
+//!
+//! let cylinder = Cylinder::new(16, 1);
+//! let graph = MeshGraph::<E3>::from_raw_buffers(
+//!     cylinder.indexing_polygons::<Position>(),
+//!     cylinder.vertices_from::<Position<E3>>(Bounds::with_radius(0.5, 2.0)),
+//! )
+//! .unwrap();
+//! ```
+
+use decorum::Real;
+use num::{One, Zero};
+use std::cmp;
+use theon::adjunct::Map;
+use theon::query::Unit;
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
+use typenum::U3;
+
+use crate::primitive::generate::{
+    AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
+    IndexingPolygonGenerator, Normal, PolygonGenerator, Position,
+};
+use crate::primitive::{into_scalar, BoundedPolygon, Tetragon, Trigon};
+
+/// Bounds of a [`Cylinder`], [`Cone`], or [`Capsule`].
+///
+/// The lower and upper radii are the radii at the bottom and top of a
+/// cylinder. Cones and capsules only use the lower radius. The height of a
+/// capsule excludes its hemispherical ends.
+#[derive(Clone, Copy)]
+pub struct Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    lower: Scalar<S>,
+    upper: Scalar<S>,
+    height: Scalar<S>,
+}
+
+impl<S> Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    pub fn with_radius(radius: Scalar<S>, height: Scalar<S>) -> Self {
+        Self::with_radii(radius, radius, height)
+    }
+
+    /// Creates bounds with different radii at the bottom and top, which form
+    /// a truncated cone.
+    pub fn with_radii(lower: Scalar<S>, upper: Scalar<S>, height: Scalar<S>) -> Self {
+        Bounds {
+            lower,
+            upper,
+            height,
+        }
+    }
+
+    pub fn unit_radius() -> Self {
+        let one = Scalar::<S>::one();
+        Self::with_radius(one, one + one)
+    }
+}
+
+impl<S> Default for Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    fn default() -> Self {
+        Self::unit_radius()
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Vertex {
+    Lower,
+    Ring(usize, usize),
+    Upper,
+}
+
+// Indexing of rings of `nu` vertices from bottom to top with optional poles
+// at either end. Rings are joined by quadrilaterals and poles are joined to
+// rings by triangles.
+#[derive(Clone, Copy)]
+struct Layout {
+    nu: usize,
+    rings: usize,
+    lower: bool,
+    upper: bool,
+}
+
+impl Layout {
+    fn vertex_count(&self) -> usize {
+        (self.rings * self.nu) + (self.lower as usize) + (self.upper as usize)
+    }
+
+    fn polygon_count(&self) -> usize {
+        ((self.rings - 1) + (self.lower as usize) + (self.upper as usize)) * self.nu
+    }
+
+    fn index_for_vertex(&self, vertex: Vertex) -> usize {
+        match vertex {
+            Vertex::Lower => 0,
+            Vertex::Ring(k, u) => (self.lower as usize) + (k * self.nu) + (u % self.nu),
+            Vertex::Upper => self.vertex_count() - 1,
+        }
+    }
+
+    fn map_vertex_index(&self, index: usize) -> Vertex {
+        if self.lower && index == 0 {
+            Vertex::Lower
+        }
+        else if self.upper && index == self.vertex_count() - 1 {
+            Vertex::Upper
+        }
+        else {
+            let index = index - (self.lower as usize);
+            Vertex::Ring(index / self.nu, index % self.nu)
+        }
+    }
+
+    fn polygon(&self, index: usize) -> BoundedPolygon<Vertex> {
+        let (offset, u) = (index / self.nu, index % self.nu);
+        let (offset, is_lower) = if self.lower {
+            (offset.wrapping_sub(1), offset == 0)
+        }
+        else {
+            (offset, false)
+        };
+        if is_lower {
+            Trigon::new(Vertex::Lower, Vertex::Ring(0, u + 1), Vertex::Ring(0, u)).into()
+        }
+        else if offset < self.rings - 1 {
+            let k = offset;
+            Tetragon::new(
+                Vertex::Ring(k, u),
+                Vertex::Ring(k, u + 1),
+                Vertex::Ring(k + 1, u + 1),
+                Vertex::Ring(k + 1, u),
+            )
+            .into()
+        }
+        else {
+            let k = self.rings - 1;
+            Trigon::new(Vertex::Upper, Vertex::Ring(k, u), Vertex::Ring(k, u + 1)).into()
+        }
+    }
+
+    fn indexing_polygon(&self, index: usize) -> BoundedPolygon<usize> {
+        self.polygon(index).map(|vertex| self.index_for_vertex(vertex))
+    }
+
+    // Gets the sine and cosine of the angle of a meridian.
+    fn meridian<S>(&self, u: usize) -> (Scalar<S>, Scalar<S>)
+    where
+        S: EuclideanSpace,
+    {
+        let one = Scalar::<S>::one();
+        let theta = (into_scalar::<_, S>(u % self.nu) / into_scalar::<_, S>(self.nu))
+            * Real::PI
+            * (one + one);
+        (theta.sin(), theta.cos())
+    }
+}
+
+/// Cylinder or truncated cone.
+///
+/// The radii of the bottom and top of the cylinder are given by its
+/// [`Bounds`]. Caps are fanned about a vertex at their centers. Faces of the
+/// sides and caps do not share normals, so the edges between them are sharp.
+#[derive(Clone, Copy)]
+pub struct Cylinder {
+    nu: usize, // Meridians.
+    nv: usize, // Segments along the axis.
+    is_capped: bool,
+}
+
+impl Cylinder {
+    pub fn new(nu: usize, nv: usize) -> Self {
+        Cylinder {
+            nu: cmp::max(3, nu),
+            nv: cmp::max(1, nv),
+            is_capped: true,
+        }
+    }
+
+    pub fn with_caps(mut self, is_capped: bool) -> Self {
+        self.is_capped = is_capped;
+        self
+    }
+
+    fn layout(&self) -> Layout {
+        Layout {
+            nu: self.nu,
+            rings: self.nv + 1,
+            lower: self.is_capped,
+            upper: self.is_capped,
+        }
+    }
+
+    fn vertex_with_position_from<S>(&self, state: &Bounds<S>, vertex: Vertex) -> S
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let zero = Zero::zero();
+        let half = state.height / (Scalar::<S>::one() + One::one());
+        match vertex {
+            Vertex::Lower => S::from_xyz(zero, zero, -half),
+            Vertex::Upper => S::from_xyz(zero, zero, half),
+            Vertex::Ring(k, u) => {
+                let t = into_scalar::<_, S>(k) / into_scalar::<_, S>(self.nv);
+                let radius = state.lower + ((state.upper - state.lower) * t);
+                let (sin, cos) = self.layout().meridian::<S>(u);
+                S::from_xyz(radius * cos, radius * sin, (state.height * t) - half)
+            }
+        }
+    }
+}
+
+impl Default for Cylinder {
+    fn default() -> Self {
+        Cylinder::new(16, 1)
+    }
+}
+
+impl PolygonGenerator for Cylinder {
+    fn polygon_count(&self) -> usize {
+        self.layout().polygon_count()
+    }
+}
+
+impl<S> AttributeGenerator<Normal<S>> for Cylinder
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Normal<S>> for Cylinder
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Unit<Vector<S>>;
+
+    fn vertex_count(&self) -> usize {
+        self.nu + if self.is_capped { 2 } else { 0 }
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        let zero = Zero::zero();
+        let one = One::one();
+        let normal = if index < self.nu {
+            // The slope of the sides of truncated cones tilts their normals.
+            let (sin, cos) = self.layout().meridian::<S>(index);
+            S::from_xyz(
+                state.height * cos,
+                state.height * sin,
+                state.lower - state.upper,
+            )
+        }
+        else if index == self.nu {
+            S::from_xyz(zero, zero, -one)
+        }
+        else {
+            S::from_xyz(zero, zero, one)
+        };
+        Unit::try_from_inner(normal.into_coordinates()).expect("non-zero vector")
+    }
+}
+
+impl<S> AttributePolygonGenerator<Normal<S>> for Cylinder
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = BoundedPolygon<Unit<Vector<S>>>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Normal<S>>::indexing_polygon(self, index)
+            .map(|index| AttributeVertexGenerator::<Normal<S>>::vertex_from(self, state, index))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Normal<S>> for Cylinder {
+    type Output = BoundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        let polygon = self.layout().polygon(index);
+        // The normals of caps are shared by all of their vertices.
+        let cap = polygon.as_ref().iter().find_map(|vertex| match vertex {
+            Vertex::Lower => Some(self.nu),
+            Vertex::Upper => Some(self.nu + 1),
+            _ => None,
+        });
+        polygon.map(|vertex| match (cap, vertex) {
+            (Some(cap), _) => cap,
+            (None, Vertex::Ring(_, u)) => u % self.nu,
+            _ => unreachable!(),
+        })
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for Cylinder
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for Cylinder
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        self.layout().vertex_count()
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        self.vertex_with_position_from(state, self.layout().map_vertex_index(index))
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for Cylinder
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = BoundedPolygon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        self.layout()
+            .polygon(index)
+            .map(|vertex| self.vertex_with_position_from(state, vertex))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Position<S>> for Cylinder {
+    type Output = BoundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        self.layout().indexing_polygon(index)
+    }
+}
+
+impl Generator for Cylinder {}
+
+/// Cone.
+///
+/// The radius of the base of the cone is the lower radius of its [`Bounds`]
+/// and its apex is on the $z$ axis. As with [`Cylinder`], the base is fanned
+/// about a vertex at its center and its edges are sharp.
+#[derive(Clone, Copy)]
+pub struct Cone {
+    nu: usize, // Meridians.
+    nv: usize, // Segments along the axis.
+    is_capped: bool,
+}
+
+impl Cone {
+    pub fn new(nu: usize, nv: usize) -> Self {
+        Cone {
+            nu: cmp::max(3, nu),
+            nv: cmp::max(1, nv),
+            is_capped: true,
+        }
+    }
+
+    pub fn with_caps(mut self, is_capped: bool) -> Self {
+        self.is_capped = is_capped;
+        self
+    }
+
+    fn layout(&self) -> Layout {
+        Layout {
+            nu: self.nu,
+            rings: self.nv,
+            lower: self.is_capped,
+            upper: true,
+        }
+    }
+
+    fn vertex_with_position_from<S>(&self, state: &Bounds<S>, vertex: Vertex) -> S
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let zero = Zero::zero();
+        let half = state.height / (Scalar::<S>::one() + One::one());
+        match vertex {
+            Vertex::Lower => S::from_xyz(zero, zero, -half),
+            Vertex::Upper => S::from_xyz(zero, zero, half),
+            Vertex::Ring(k, u) => {
+                let t = into_scalar::<_, S>(k) / into_scalar::<_, S>(self.nv);
+                let radius = state.lower - (state.lower * t);
+                let (sin, cos) = self.layout().meridian::<S>(u);
+                S::from_xyz(radius * cos, radius * sin, (state.height * t) - half)
+            }
+        }
+    }
+}
+
+impl Default for Cone {
+    fn default() -> Self {
+        Cone::new(16, 1)
+    }
+}
+
+impl PolygonGenerator for Cone {
+    fn polygon_count(&self) -> usize {
+        self.layout().polygon_count()
+    }
+}
+
+impl<S> AttributeGenerator<Normal<S>> for Cone
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Normal<S>> for Cone
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Unit<Vector<S>>;
+
+    fn vertex_count(&self) -> usize {
+        self.nu + if self.is_capped { 1 } else { 0 }
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        let normal = if index < self.nu {
+            let (sin, cos) = self.layout().meridian::<S>(index);
+            S::from_xyz(state.height * cos, state.height * sin, state.lower)
+        }
+        else {
+            let zero = Zero::zero();
+            S::from_xyz(zero, zero, -Scalar::<S>::one())
+        };
+        Unit::try_from_inner(normal.into_coordinates()).expect("non-zero vector")
+    }
+}
+
+impl<S> AttributePolygonGenerator<Normal<S>> for Cone
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = BoundedPolygon<Unit<Vector<S>>>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Normal<S>>::indexing_polygon(self, index)
+            .map(|index| AttributeVertexGenerator::<Normal<S>>::vertex_from(self, state, index))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Normal<S>> for Cone {
+    type Output = BoundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        let polygon = self.layout().polygon(index);
+        let is_base = polygon
+            .as_ref()
+            .iter()
+            .any(|vertex| matches!(vertex, Vertex::Lower));
+        // The apex has no well-defined normal, so it shares the normal of the
+        // meridian of the triangle.
+        let u = index % self.nu;
+        polygon.map(|vertex| match vertex {
+            _ if is_base => self.nu,
+            Vertex::Ring(_, u) => u % self.nu,
+            _ => u,
+        })
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for Cone
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for Cone
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        self.layout().vertex_count()
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        self.vertex_with_position_from(state, self.layout().map_vertex_index(index))
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for Cone
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = BoundedPolygon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        self.layout()
+            .polygon(index)
+            .map(|vertex| self.vertex_with_position_from(state, vertex))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Position<S>> for Cone {
+    type Output = BoundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        self.layout().indexing_polygon(index)
+    }
+}
+
+impl Generator for Cone {}
+
+/// Capsule.
+///
+/// A capsule is a cylinder with hemispherical ends. Its radius is the lower
+/// radius of its [`Bounds`] and its height is the height of the cylinder
+/// between its ends. Unlike [`Cylinder`], the surface of a capsule is smooth
+/// and positions and normals are indexed in the same way.
+#[derive(Clone, Copy)]
+pub struct Capsule {
+    nu: usize, // Meridians.
+    nv: usize, // Parallels of each end.
+    nh: usize, // Segments of the cylinder.
+}
+
+impl Capsule {
+    pub fn new(nu: usize, nv: usize, nh: usize) -> Self {
+        Capsule {
+            nu: cmp::max(3, nu),
+            nv: cmp::max(1, nv),
+            nh: cmp::max(1, nh),
+        }
+    }
+
+    fn layout(&self) -> Layout {
+        Layout {
+            nu: self.nu,
+            rings: (2 * self.nv) + self.nh - 1,
+            lower: true,
+            upper: true,
+        }
+    }
+
+    // Gets the position of a vertex on a unit sphere and the offset of the
+    // vertex along the $z$ axis as a proportion of the height of the capsule.
+    fn vertex_with_normal_from<S>(&self, vertex: Vertex) -> (Vector<S>, Scalar<S>)
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let zero = Zero::zero();
+        let one = Scalar::<S>::one();
+        let half = one / (one + one);
+        let (x, y, z, offset) = match vertex {
+            Vertex::Lower => (zero, zero, -one, -half),
+            Vertex::Upper => (zero, zero, one, half),
+            Vertex::Ring(k, u) => {
+                let (sin, cos) = self.layout().meridian::<S>(u);
+                let quarter = Real::PI * half;
+                let (phi, offset) = if k < self.nv {
+                    // Lower end.
+                    let angle = quarter * into_scalar::<_, S>(self.nv - 1 - k)
+                        / into_scalar::<_, S>(self.nv);
+                    (-angle, -half)
+                }
+                else if k < self.nv + self.nh {
+                    // Cylinder.
+                    let t = into_scalar::<_, S>(k + 1 - self.nv) / into_scalar::<_, S>(self.nh);
+                    (zero, t - half)
+                }
+                else {
+                    // Upper end.
+                    let angle = quarter * into_scalar::<_, S>(k + 1 - self.nv - self.nh)
+                        / into_scalar::<_, S>(self.nv);
+                    (angle, half)
+                };
+                (cos * phi.cos(), sin * phi.cos(), phi.sin(), offset)
+            }
+        };
+        let normal = S::from_xyz(x, y, z) - S::origin();
+        (normal, offset)
+    }
+}
+
+impl Default for Capsule {
+    fn default() -> Self {
+        Capsule::new(16, 4, 1)
+    }
+}
+
+impl PolygonGenerator for Capsule {
+    fn polygon_count(&self) -> usize {
+        self.layout().polygon_count()
+    }
+}
+
+impl<S> AttributeGenerator<Normal<S>> for Capsule
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<Normal<S>> for Capsule
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Unit<Vector<S>>;
+
+    fn vertex_count(&self) -> usize {
+        self.layout().vertex_count()
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        let (normal, _) = self.vertex_with_normal_from::<S>(self.layout().map_vertex_index(index));
+        Unit::try_from_inner(normal).expect("non-zero vector")
+    }
+}
+
+impl<S> AttributePolygonGenerator<Normal<S>> for Capsule
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = BoundedPolygon<Unit<Vector<S>>>;
+
+    fn polygon_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        self.layout().polygon(index).map(|vertex| {
+            let (normal, _) = self.vertex_with_normal_from::<S>(vertex);
+            Unit::try_from_inner(normal).expect("non-zero vector")
+        })
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Normal<S>> for Capsule {
+    type Output = BoundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        self.layout().indexing_polygon(index)
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for Capsule
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for Capsule
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        self.layout().vertex_count()
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        let vertex = self.layout().map_vertex_index(index);
+        let (normal, offset) = self.vertex_with_normal_from::<S>(vertex);
+        let zero = Zero::zero();
+        S::from_xyz(zero, zero, state.height * offset) + (normal * state.lower)
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for Capsule
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = BoundedPolygon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        self.layout().polygon(index).map(|vertex| {
+            let (normal, offset) = self.vertex_with_normal_from::<S>(vertex);
+            let zero = Zero::zero();
+            S::from_xyz(zero, zero, state.height * offset) + (normal * state.lower)
+        })
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Position<S>> for Capsule {
+    type Output = BoundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        self.layout().indexing_polygon(index)
+    }
+}
+
+impl Generator for Capsule {}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+    use std::f64::consts::PI;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::cylinder::{Bounds, Capsule, Cone, Cylinder};
+    use crate::primitive::generate::{Normal, Position};
+
+    type E3 = Point3<f64>;
+
+    // The volume of a regular polygonal prism with `n` sides inscribed in a
+    // circle with the given radius.
+    fn prism(n: usize, radius: f64, height: f64) -> f64 {
+        0.5 * (n as f64) * radius * radius * (2.0 * PI / (n as f64)).sin() * height
+    }
+
+    #[test]
+    fn cylinder_volume() {
+        let cylinder = Cylinder::new(16, 3);
+        assert_eq!(16 * 4 + 2, cylinder.vertices::<Position<E3>>().count());
+        assert_eq!(16 * 5, cylinder.polygons::<Position<E3>>().count());
+        let graph = MeshGraph::<E3>::from_raw_buffers(
+            cylinder.indexing_polygons::<Position>(),
+            cylinder.vertices_from::<Position<E3>>(Bounds::with_radius(0.5, 2.0)),
+        )
+        .unwrap();
+        assert!((graph.volume().unwrap() - prism(16, 0.5, 2.0)).abs() < 1e-9);
+
+        // Caps share a single normal.
+        assert_eq!(18, cylinder.vertices::<Normal<E3>>().count());
+        assert_eq!(
+            16,
+            Cylinder::new(16, 3)
+                .with_caps(false)
+                .vertices::<Normal<E3>>()
+                .count()
+        );
+    }
+
+    #[test]
+    fn cone_volume() {
+        let cone = Cone::new(16, 2);
+        assert_eq!(16 * 2 + 2, cone.vertices::<Position<E3>>().count());
+        let graph = MeshGraph::<E3>::from_raw_buffers(
+            cone.indexing_polygons::<Position>(),
+            cone.vertices_from::<Position<E3>>(Bounds::with_radius(1.0, 3.0)),
+        )
+        .unwrap();
+        assert!((graph.volume().unwrap() - (prism(16, 1.0, 3.0) / 3.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn capsule_is_smooth_and_closed() {
+        let capsule = Capsule::new(32, 8, 2);
+        let graph = MeshGraph::<E3>::from_raw_buffers(
+            capsule.indexing_polygons::<Position>(),
+            capsule.vertices_from::<Position<E3>>(Bounds::with_radius(0.5, 1.0)),
+        )
+        .unwrap();
+        let expected = (PI * 0.25 * 1.0) + ((4.0 / 3.0) * PI * 0.125);
+        let volume = graph.volume().unwrap();
+        assert!(((volume - expected) / expected).abs() < 0.05);
+        // Every position is at the radius from the segment of the axis.
+        assert!(graph.vertices().all(|vertex| {
+            let position = vertex.position();
+            let z = position.z.max(-0.5).min(0.5);
+            ((position - E3::new(0.0, 0.0, z)).norm() - 0.5).abs() < 1e-9
+        }));
+        assert_eq!(
+            capsule.vertices::<Position<E3>>().count(),
+            capsule.vertices::<Normal<E3>>().count()
+        );
+    }
+}
@@ -135,6 +135,48 @@ pub struct Position<S = ()> {
 
 impl<S> Attribute for Position<S> {}
 
+/// Meta-attribute for texture coordinates.
+///
+/// Describes the $uv$-mapping of a polytope. The generated data is derived from
+/// the type parameter `S`, which typically requires a two-dimensional
+/// [`EuclideanSpace`]. Texture coordinates are in the unit square.
+///
+/// Texture coordinates are indexed independently of positions. For example,
+/// the eight positions of a [`Cube`] are shared by three faces each, but each
+/// face has distinct texture coordinates.
+///
+/// # Examples
+///
+/// Generating raw buffers with texture coordinates of a
+/// [$uv$-sphere][`UvSphere`]:
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point2;
+/// use plexus::prelude::*;
+/// use plexus::primitive::generate::UvMap;
+/// use plexus::primitive::sphere::UvSphere;
+///
+/// let sphere = UvSphere::new(16, 8);
+/// let uvs = sphere.vertices::<UvMap<Point2<f64>>>().collect::<Vec<_>>();
+/// let indices = sphere
+///     .indexing_polygons::<UvMap>()
+///     .triangulate()
+///     .vertices()
+///     .collect::<Vec<_>>();
+/// ```
+///
+/// [`EuclideanSpace`]: theon::space::EuclideanSpace
+/// [`Cube`]: crate::primitive::cube::Cube
+/// [`UvSphere`]: crate::primitive::sphere::UvSphere
+pub struct UvMap<S = ()> {
+    phantom: PhantomData<fn() -> S>,
+}
+
+impl<S> Attribute for UvMap<S> {}
+
 /// Iterator that generates topology and geometric attributes.
 pub struct Generate<'a, G, S, P>
 where
@@ -0,0 +1,472 @@
+//! Planar grid and heightfield primitives.
+//!
+//! Grids lie in the $xy$ plane, are centered at the origin, and face the
+//! positive $z$ axis. Heightfields displace the vertices of a grid along the
+//! $z$ axis.
+//!
+//! # Examples
+//!
+//! Generating a buffer from a heightfield:
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use nalgebra::Point3;
+//! use plexus::buffer::MeshBuffer4;
+//! use plexus::prelude::*;
+//! use plexus::primitive::generate::Position;
+//! use plexus::primitive::grid::{Bounds, Heightfield};
+//!
+//! type E3 = Point3<f64>;
+//!
+//! let heightfield = Heightfield::<E3>::from_fn(32, 32, |u, v| {
+//!     (u * 6.0).sin() * (v * 6.0).cos()
+//! });
+//! let buffer = MeshBuffer4::<usize, E3>::from_raw_buffers(
+//!     heightfield.indexing_polygons::<Position>(),
+//!     heightfield.vertices_from::<Position<E3>>(Bounds::with_extent(16.0, 16.0)),
+//! )
+//! .unwrap();
+//! ```
+
+use num::{One, Zero};
+use std::cmp;
+use theon::adjunct::Map;
+use theon::query::Unit;
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
+use typenum::U3;
+
+use crate::primitive::generate::{
+    AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
+    IndexingPolygonGenerator, Normal, PolygonGenerator, Position,
+};
+use crate::primitive::{into_scalar, Tetragon};
+
+#[derive(Clone, Copy)]
+pub struct Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    width: Scalar<S>,
+    depth: Scalar<S>,
+}
+
+impl<S> Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    /// Creates bounds with a width along the $x$ axis and a depth along the
+    /// $y$ axis.
+    pub fn with_extent(width: Scalar<S>, depth: Scalar<S>) -> Self {
+        Bounds { width, depth }
+    }
+
+    pub fn unit_width() -> Self {
+        Self::with_extent(One::one(), One::one())
+    }
+}
+
+impl<S> Default for Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    fn default() -> Self {
+        Self::unit_width()
+    }
+}
+
+/// Planar grid of quadrilaterals.
+#[derive(Clone, Copy)]
+pub struct Grid {
+    nu: usize, // Columns.
+    nv: usize, // Rows.
+}
+
+impl Grid {
+    pub fn new(nu: usize, nv: usize) -> Self {
+        Grid {
+            nu: cmp::max(1, nu),
+            nv: cmp::max(1, nv),
+        }
+    }
+
+    fn vertex_with_position_from<S>(
+        &self,
+        state: &Bounds<S>,
+        u: usize,
+        v: usize,
+        height: Scalar<S>,
+    ) -> S
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let half = Scalar::<S>::one() / (Scalar::<S>::one() + One::one());
+        let (s, t) = self.map_to_unit::<S>(u, v);
+        S::from_xyz(state.width * (s - half), state.depth * (t - half), height)
+    }
+
+    // Maps `(u, v)` into the unit square.
+    fn map_to_unit<S>(&self, u: usize, v: usize) -> (Scalar<S>, Scalar<S>)
+    where
+        S: EuclideanSpace,
+    {
+        (
+            into_scalar::<_, S>(u) / into_scalar::<_, S>(self.nu),
+            into_scalar::<_, S>(v) / into_scalar::<_, S>(self.nv),
+        )
+    }
+
+    fn index_for_position(&self, u: usize, v: usize) -> usize {
+        (v * (self.nu + 1)) + u
+    }
+
+    fn map_vertex_index(&self, index: usize) -> (usize, usize) {
+        (index % (self.nu + 1), index / (self.nu + 1))
+    }
+
+    fn position_count(&self) -> usize {
+        (self.nu + 1) * (self.nv + 1)
+    }
+}
+
+impl Default for Grid {
+    fn default() -> Self {
+        Grid::new(1, 1)
+    }
+}
+
+impl PolygonGenerator for Grid {
+    fn polygon_count(&self) -> usize {
+        self.nu * self.nv
+    }
+}
+
+impl<S> AttributeGenerator<Normal<S>> for Grid
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<Normal<S>> for Grid
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Unit<Vector<S>>;
+
+    fn vertex_count(&self) -> usize {
+        1
+    }
+
+    fn vertex_from(&self, _: &Self::State, _: usize) -> Self::Output {
+        let zero = Zero::zero();
+        Unit::try_from_inner(S::from_xyz(zero, zero, One::one()).into_coordinates())
+            .expect("non-zero vector")
+    }
+}
+
+impl<S> AttributePolygonGenerator<Normal<S>> for Grid
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Tetragon<Unit<Vector<S>>>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Normal<S>>::indexing_polygon(self, index)
+            .map(|index| AttributeVertexGenerator::<Normal<S>>::vertex_from(self, state, index))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Normal<S>> for Grid {
+    type Output = Tetragon<usize>;
+
+    fn indexing_polygon(&self, _: usize) -> Self::Output {
+        Tetragon::new(0, 0, 0, 0)
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for Grid
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for Grid
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        self.position_count()
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        let (u, v) = self.map_vertex_index(index);
+        self.vertex_with_position_from(state, u, v, Zero::zero())
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for Grid
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Tetragon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Position<S>>::indexing_polygon(self, index)
+            .map(|index| AttributeVertexGenerator::<Position<S>>::vertex_from(self, state, index))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Position<S>> for Grid {
+    type Output = Tetragon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        let (u, v) = (index % self.nu, index / self.nu);
+        Tetragon::new(
+            self.index_for_position(u, v),
+            self.index_for_position(u + 1, v),
+            self.index_for_position(u + 1, v + 1),
+            self.index_for_position(u, v + 1),
+        )
+    }
+}
+
+impl Generator for Grid {}
+
+/// [`Grid`] displaced along the $z$ axis by heights.
+///
+/// Heights are given for each vertex of the grid and are independent of its
+/// [`Bounds`]. Normals are approximated from the differences between the
+/// heights of adjacent vertices.
+#[derive(Clone)]
+pub struct Heightfield<S>
+where
+    S: EuclideanSpace,
+{
+    grid: Grid,
+    heights: Vec<Scalar<S>>,
+}
+
+impl<S> Heightfield<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    /// Creates a heightfield with `nu` columns and `nv` rows from a function.
+    ///
+    /// The function receives the coordinates of each vertex in the unit
+    /// square, where $(0, 0)$ and $(1, 1)$ are the minimum and maximum
+    /// corners of the grid.
+    pub fn from_fn<F>(nu: usize, nv: usize, mut f: F) -> Self
+    where
+        F: FnMut(Scalar<S>, Scalar<S>) -> Scalar<S>,
+    {
+        let grid = Grid::new(nu, nv);
+        let heights = (0..grid.position_count())
+            .map(|index| {
+                let (u, v) = grid.map_vertex_index(index);
+                let (s, t) = grid.map_to_unit::<S>(u, v);
+                f(s, t)
+            })
+            .collect();
+        Heightfield { grid, heights }
+    }
+
+    /// Creates a heightfield with `nu` columns and `nv` rows from samples.
+    ///
+    /// There is a sample for each vertex, so there are `nu + 1` samples in
+    /// each of `nv + 1` rows. Samples are ordered by row, beginning with the
+    /// row at the minimum of the $y$ axis.
+    ///
+    /// Returns `None` if the number of samples does not match the number of
+    /// vertices.
+    pub fn from_samples<I>(nu: usize, nv: usize, samples: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Scalar<S>>,
+    {
+        let grid = Grid::new(nu, nv);
+        let heights = samples.into_iter().collect::<Vec<_>>();
+        if heights.len() == grid.position_count() {
+            Some(Heightfield { grid, heights })
+        }
+        else {
+            None
+        }
+    }
+
+    fn height(&self, u: usize, v: usize) -> Scalar<S> {
+        self.heights[self.grid.index_for_position(u, v)]
+    }
+
+    fn vertex_with_normal_from(&self, state: &Bounds<S>, index: usize) -> Unit<Vector<S>> {
+        let (nu, nv) = (self.grid.nu, self.grid.nv);
+        let (u, v) = self.grid.map_vertex_index(index);
+        // Use central differences in the interior and one-sided differences
+        // on the boundary.
+        let (u0, u1) = (u.saturating_sub(1), cmp::min(u + 1, nu));
+        let (v0, v1) = (v.saturating_sub(1), cmp::min(v + 1, nv));
+        let dx = state.width * into_scalar::<_, S>(u1 - u0) / into_scalar::<_, S>(nu);
+        let dy = state.depth * into_scalar::<_, S>(v1 - v0) / into_scalar::<_, S>(nv);
+        let dzdx = (self.height(u1, v) - self.height(u0, v)) / dx;
+        let dzdy = (self.height(u, v1) - self.height(u, v0)) / dy;
+        let normal = S::from_xyz(-dzdx, -dzdy, One::one()) - S::origin();
+        Unit::try_from_inner(normal).expect("non-zero vector")
+    }
+}
+
+impl<S> PolygonGenerator for Heightfield<S>
+where
+    S: EuclideanSpace,
+{
+    fn polygon_count(&self) -> usize {
+        self.grid.polygon_count()
+    }
+}
+
+impl<S> AttributeGenerator<Normal<S>> for Heightfield<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Normal<S>> for Heightfield<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Unit<Vector<S>>;
+
+    fn vertex_count(&self) -> usize {
+        self.grid.position_count()
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        self.vertex_with_normal_from(state, index)
+    }
+}
+
+impl<S> AttributePolygonGenerator<Normal<S>> for Heightfield<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Tetragon<Unit<Vector<S>>>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Position<S>>::indexing_polygon(&self.grid, index)
+            .map(|index| self.vertex_with_normal_from(state, index))
+    }
+}
+
+impl<S, T> IndexingPolygonGenerator<Normal<T>> for Heightfield<S>
+where
+    S: EuclideanSpace,
+{
+    type Output = Tetragon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Position<T>>::indexing_polygon(&self.grid, index)
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for Heightfield<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for Heightfield<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        self.grid.position_count()
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        let (u, v) = self.grid.map_vertex_index(index);
+        self.grid.vertex_with_position_from(state, u, v, self.heights[index])
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for Heightfield<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Tetragon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Position<S>>::indexing_polygon(&self.grid, index)
+            .map(|index| AttributeVertexGenerator::<Position<S>>::vertex_from(self, state, index))
+    }
+}
+
+impl<S, T> IndexingPolygonGenerator<Position<T>> for Heightfield<S>
+where
+    S: EuclideanSpace,
+{
+    type Output = Tetragon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Position<T>>::indexing_polygon(&self.grid, index)
+    }
+}
+
+impl<S> Generator for Heightfield<S> where S: EuclideanSpace {}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Point3, Vector3};
+
+    use crate::buffer::MeshBuffer4;
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::generate::{Normal, Position};
+    use crate::primitive::grid::{Bounds, Grid, Heightfield};
+
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn grid_vertex_and_polygon_count() {
+        let grid = Grid::new(4, 3);
+        assert_eq!(20, grid.vertices::<Position<E3>>().count());
+        assert_eq!(12, grid.polygons::<Position<E3>>().count());
+
+        let buffer = MeshBuffer4::<usize, E3>::from_raw_buffers(
+            grid.indexing_polygons::<Position>(),
+            grid.vertices_from::<Position<E3>>(Bounds::with_extent(4.0, 3.0)),
+        )
+        .unwrap();
+        assert_eq!(12, buffer.as_index_slice().len());
+        // Faces face the positive $z$ axis.
+        let graph = MeshGraph::<E3>::try_from(buffer).unwrap();
+        assert!(graph
+            .faces()
+            .all(|face| (face.normal().unwrap() - Vector3::z()).norm() < 1e-9));
+        assert!((graph.area() - 12.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn heightfield_slope() {
+        // A plane that rises along the $x$ axis with a slope of one half.
+        let heightfield = Heightfield::<E3>::from_fn(4, 4, |u, _| u);
+        let bounds = Bounds::with_extent(2.0, 2.0);
+        let positions = heightfield
+            .vertices_from::<Position<E3>>(bounds)
+            .collect::<Vec<_>>();
+        assert_eq!(E3::new(-1.0, -1.0, 0.0), positions[0]);
+        assert_eq!(E3::new(1.0, 1.0, 1.0), positions[24]);
+        let expected = Vector3::new(-0.5, 0.0, 1.0).normalize();
+        assert!(heightfield
+            .vertices_from::<Normal<E3>>(bounds)
+            .all(|normal| (normal.into_inner() - expected).norm() < 1e-9));
+
+        assert!(Heightfield::<E3>::from_samples(2, 2, vec![0.0; 8]).is_none());
+        assert!(Heightfield::<E3>::from_samples(2, 2, vec![0.0; 9]).is_some());
+    }
+}
@@ -0,0 +1,474 @@
+//! Isosurface primitives.
+//!
+//! This module provides polygonization of scalar fields via marching cubes. A
+//! [`ScalarField`] is sampled on a regular grid, either from a function or
+//! from an array of samples, and [`MarchingCubes`] extracts the isosurface of
+//! the field at a given level as triangles.
+//!
+//! Points where the field is less than the level are considered inside of the
+//! surface, so signed distance functions can be polygonized at a level of
+//! zero. Triangles are wound such that their normals point outward (toward
+//! greater values of the field).
+//!
+//! # Examples
+//!
+//! Generating a graph from a sphere described by a signed distance function:
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use nalgebra::Point3;
+//! use plexus::graph::MeshGraph;
+//! use plexus::prelude::*;
+//! use plexus::primitive::generate::Position;
+//! use plexus::primitive::isosurface::{MarchingCubes, ScalarField};
+//!
+//! type E3 = Point3<f64>;
+//!
+//! let field = ScalarField::from_fn(
+//!     E3::new(-1.0, -1.0, -1.0),
+//!     E3::new(1.0, 1.0, 1.0),
+//!     (16, 16, 16),
+//!     |point: E3| point.coords.norm() - 0.75,
+//! );
+//! let cubes = MarchingCubes::new(&field, 0.0);
+//! let graph = MeshGraph::<E3>::from_raw_buffers(
+//!     cubes.indexing_polygons::<Position>(),
+//!     cubes.vertices::<Position<E3>>(),
+//! )
+//! .unwrap();
+//! ```
+
+use num::Zero;
+use smallvec::SmallVec;
+use std::cmp;
+use std::collections::HashMap;
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar};
+use typenum::U3;
+
+use crate::primitive::generate::{
+    AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
+    IndexingPolygonGenerator, PolygonGenerator, Position,
+};
+use crate::primitive::{into_scalar, Trigon};
+
+// Corners of a cell are indexed such that bit `n` is the offset of the corner
+// along axis `n`. Edges are pairs of corners, lower corner first.
+const EDGES: [(usize, usize); 12] = [
+    (0, 1),
+    (2, 3),
+    (4, 5),
+    (6, 7),
+    (0, 2),
+    (1, 3),
+    (4, 6),
+    (5, 7),
+    (0, 4),
+    (1, 5),
+    (2, 6),
+    (3, 7),
+];
+
+// Faces of a cell are cycles of corners wound counterclockwise when viewed
+// from outside of the cell.
+const FACES: [[usize; 4]; 6] = [
+    [0, 2, 3, 1],
+    [4, 5, 7, 6],
+    [0, 1, 5, 4],
+    [2, 6, 7, 3],
+    [0, 4, 6, 2],
+    [1, 3, 7, 5],
+];
+
+/// Scalar field sampled on a regular grid.
+///
+/// The grid spans an axis-aligned box between a lower and upper point and has
+/// a number of samples along each axis.
+#[derive(Clone)]
+pub struct ScalarField<S>
+where
+    S: EuclideanSpace,
+{
+    lower: [Scalar<S>; 3],
+    step: [Scalar<S>; 3],
+    dimensions: [usize; 3],
+    samples: Vec<Scalar<S>>,
+}
+
+impl<S> ScalarField<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    /// Samples a field from a function.
+    ///
+    /// `dimensions` is the number of samples along each axis and is clamped to
+    /// a minimum of two.
+    pub fn from_fn<F>(lower: S, upper: S, dimensions: (usize, usize, usize), mut f: F) -> Self
+    where
+        F: FnMut(S) -> Scalar<S>,
+    {
+        let (nx, ny, nz) = dimensions;
+        let mut field = ScalarField::with_dimensions(
+            lower,
+            upper,
+            [cmp::max(2, nx), cmp::max(2, ny), cmp::max(2, nz)],
+        );
+        let [nx, ny, nz] = field.dimensions;
+        field.samples = (0..nz)
+            .flat_map(|k| (0..ny).flat_map(move |j| (0..nx).map(move |i| [i, j, k])))
+            .map(|index| f(field.position(index)))
+            .collect();
+        field
+    }
+
+    /// Creates a field from samples.
+    ///
+    /// Samples are ordered by their position along the $x$ axis, then the $y$
+    /// axis, then the $z$ axis. That is, the $x$ index varies fastest.
+    ///
+    /// Returns `None` if any of the `dimensions` is less than two or the
+    /// number of samples does not match the `dimensions`.
+    pub fn from_samples<I>(
+        lower: S,
+        upper: S,
+        dimensions: (usize, usize, usize),
+        samples: I,
+    ) -> Option<Self>
+    where
+        I: IntoIterator<Item = Scalar<S>>,
+    {
+        let (nx, ny, nz) = dimensions;
+        if nx < 2 || ny < 2 || nz < 2 {
+            return None;
+        }
+        let mut field = ScalarField::with_dimensions(lower, upper, [nx, ny, nz]);
+        field.samples = samples.into_iter().collect();
+        if field.samples.len() == nx * ny * nz {
+            Some(field)
+        }
+        else {
+            None
+        }
+    }
+
+    fn with_dimensions(lower: S, upper: S, dimensions: [usize; 3]) -> Self {
+        let (lx, ly, lz) = lower.into_xyz();
+        let (ux, uy, uz) = upper.into_xyz();
+        let step = |lower: Scalar<S>, upper: Scalar<S>, n: usize| {
+            (upper - lower) / into_scalar::<_, S>(n - 1)
+        };
+        ScalarField {
+            lower: [lx, ly, lz],
+            step: [
+                step(lx, ux, dimensions[0]),
+                step(ly, uy, dimensions[1]),
+                step(lz, uz, dimensions[2]),
+            ],
+            dimensions,
+            samples: Vec::new(),
+        }
+    }
+
+    fn position(&self, index: [usize; 3]) -> S {
+        let x = |n: usize| self.lower[n] + (self.step[n] * into_scalar::<_, S>(index[n]));
+        S::from_xyz(x(0), x(1), x(2))
+    }
+
+    fn sample(&self, index: [usize; 3]) -> Scalar<S> {
+        self.samples[self.flatten(index)]
+    }
+
+    fn flatten(&self, index: [usize; 3]) -> usize {
+        let [nx, ny, _] = self.dimensions;
+        index[0] + (nx * (index[1] + (ny * index[2])))
+    }
+}
+
+/// Isosurface of a [`ScalarField`] extracted via marching cubes.
+///
+/// The surface is polygonized when constructed and its triangles and positions
+/// are exposed via [`Generator`]. Positions are shared between the triangles
+/// of adjacent cells, so [`indexing_polygons`] and [`vertices`] can be used to
+/// construct closed meshes without an [`Indexer`].
+///
+/// Cells are polygonized by tracing the contour of the surface along their
+/// faces rather than via lookup tables. Faces with ambiguous contours are
+/// resolved using the mean of their samples, which is the same for the cells
+/// on either side of a face, so the surface is free of cracks and is closed
+/// wherever it does not meet the boundary of the field.
+///
+/// [`Generator`]: crate::primitive::generate::Generator
+/// [`Indexer`]: crate::index::Indexer
+/// [`indexing_polygons`]: crate::primitive::generate::Generator::indexing_polygons
+/// [`vertices`]: crate::primitive::generate::Generator::vertices
+#[derive(Clone)]
+pub struct MarchingCubes<S>
+where
+    S: EuclideanSpace,
+{
+    positions: Vec<S>,
+    polygons: Vec<Trigon<usize>>,
+}
+
+impl<S> MarchingCubes<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    /// Extracts the isosurface of a field at the given level.
+    pub fn new(field: &ScalarField<S>, level: Scalar<S>) -> Self {
+        let mut cubes = MarchingCubes {
+            positions: Vec::new(),
+            polygons: Vec::new(),
+        };
+        // Positions are keyed by the grid index of the lower corner of their
+        // edge and the axis of the edge.
+        let mut keys = HashMap::<(usize, usize), usize>::new();
+        let [nx, ny, nz] = field.dimensions;
+        for k in 0..(nz - 1) {
+            for j in 0..(ny - 1) {
+                for i in 0..(nx - 1) {
+                    cubes.polygonize(field, level, [i, j, k], &mut keys);
+                }
+            }
+        }
+        cubes
+    }
+
+    fn polygonize(
+        &mut self,
+        field: &ScalarField<S>,
+        level: Scalar<S>,
+        cell: [usize; 3],
+        keys: &mut HashMap<(usize, usize), usize>,
+    ) {
+        let corner = |n: usize| {
+            [
+                cell[0] + (n & 1),
+                cell[1] + ((n >> 1) & 1),
+                cell[2] + ((n >> 2) & 1),
+            ]
+        };
+        let mut values = [level; 8];
+        for (n, value) in values.iter_mut().enumerate() {
+            *value = field.sample(corner(n));
+        }
+        let is_inside = |n: usize| values[n] < level;
+        if (0..8).all(is_inside) || !(0..8).any(is_inside) {
+            return;
+        }
+        // Trace the contour along each face of the cell. Contour segments
+        // begin at an edge where the face enters the surface and end at an
+        // edge where it exits, so segments link into cycles about the cell.
+        let mut next = [None; 12];
+        for face in FACES.iter() {
+            let face_edge = |n: usize| {
+                let (a, b) = (face[n], face[(n + 1) % 4]);
+                edge(cmp::min(a, b), cmp::max(a, b))
+            };
+            let entering = (0..4)
+                .filter(|n| !is_inside(face[*n]) && is_inside(face[(n + 1) % 4]))
+                .collect::<SmallVec<[_; 2]>>();
+            match entering.len() {
+                0 => {}
+                1 => {
+                    let exiting = (0..4)
+                        .find(|n| is_inside(face[*n]) && !is_inside(face[(n + 1) % 4]))
+                        .unwrap();
+                    next[face_edge(entering[0])] = Some(face_edge(exiting));
+                }
+                _ => {
+                    // The contour is ambiguous. If the mean of the samples is
+                    // inside of the surface, then the inside corners are
+                    // connected across the face and each entering edge is
+                    // linked to the preceding exiting edge. Otherwise, the
+                    // inside corners are separated.
+                    let mean = face
+                        .iter()
+                        .fold(Zero::zero(), |sum: Scalar<S>, n| sum + values[*n])
+                        / into_scalar::<_, S>(4);
+                    let offset = if mean < level { 3 } else { 1 };
+                    for n in entering {
+                        next[face_edge(n)] = Some(face_edge((n + offset) % 4));
+                    }
+                }
+            }
+        }
+        let mut is_visited = [false; 12];
+        for start in 0..12 {
+            if is_visited[start] || next[start].is_none() {
+                continue;
+            }
+            let mut ring = SmallVec::<[usize; 12]>::new();
+            let mut n = start;
+            while !is_visited[n] {
+                is_visited[n] = true;
+                ring.push(self.position_index(field, level, cell, n, keys));
+                match next[n] {
+                    Some(m) => {
+                        n = m;
+                    }
+                    None => break,
+                }
+            }
+            if ring.len() < 3 {
+                continue;
+            }
+            for (b, c) in ring[1..].iter().zip(ring[2..].iter()) {
+                self.polygons.push(Trigon::new(ring[0], *b, *c));
+            }
+        }
+    }
+
+    fn position_index(
+        &mut self,
+        field: &ScalarField<S>,
+        level: Scalar<S>,
+        cell: [usize; 3],
+        edge: usize,
+        keys: &mut HashMap<(usize, usize), usize>,
+    ) -> usize {
+        let (a, b) = EDGES[edge];
+        let axis = (a ^ b).trailing_zeros() as usize;
+        let lower = [
+            cell[0] + (a & 1),
+            cell[1] + ((a >> 1) & 1),
+            cell[2] + ((a >> 2) & 1),
+        ];
+        let positions = &mut self.positions;
+        *keys
+            .entry((field.flatten(lower), axis))
+            .or_insert_with(|| {
+                let mut upper = lower;
+                upper[axis] += 1;
+                let (p, q) = (field.position(lower), field.position(upper));
+                let (u, v) = (field.sample(lower), field.sample(upper));
+                let t = (level - u) / (v - u);
+                positions.push(p + ((q - p) * t));
+                positions.len() - 1
+            })
+    }
+}
+
+impl<S> PolygonGenerator for MarchingCubes<S>
+where
+    S: EuclideanSpace,
+{
+    fn polygon_count(&self) -> usize {
+        self.polygons.len()
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for MarchingCubes<S>
+where
+    S: EuclideanSpace,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for MarchingCubes<S>
+where
+    S: EuclideanSpace,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        self.positions[index]
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for MarchingCubes<S>
+where
+    S: EuclideanSpace,
+{
+    type Output = Trigon<S>;
+
+    fn polygon_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        let [a, b, c] = self.polygons[index].into_array();
+        Trigon::new(self.positions[a], self.positions[b], self.positions[c])
+    }
+}
+
+impl<S, T> IndexingPolygonGenerator<Position<T>> for MarchingCubes<S>
+where
+    S: EuclideanSpace,
+{
+    type Output = Trigon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        self.polygons[index]
+    }
+}
+
+impl<S> Generator for MarchingCubes<S> where S: EuclideanSpace {}
+
+fn edge(a: usize, b: usize) -> usize {
+    EDGES
+        .iter()
+        .position(|edge| *edge == (a, b))
+        .expect("no edge between corners")
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::generate::Position;
+    use crate::primitive::isosurface::{MarchingCubes, ScalarField};
+
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn sphere_is_closed() {
+        let radius = 0.75;
+        let field = ScalarField::from_fn(
+            E3::new(-1.0, -1.0, -1.0),
+            E3::new(1.0, 1.0, 1.0),
+            (24, 24, 24),
+            |point: E3| point.coords.norm() - radius,
+        );
+        let cubes = MarchingCubes::new(&field, 0.0);
+        let graph = MeshGraph::<E3>::from_raw_buffers(
+            cubes.indexing_polygons::<Position>(),
+            cubes.vertices::<Position<E3>>(),
+        )
+        .unwrap();
+
+        assert!(graph
+            .vertices()
+            .all(|vertex| (vertex.position().coords.norm() - radius).abs() < 0.05));
+        // The volume is only defined for closed graphs and is positive if the
+        // faces of the graph are wound outward.
+        let volume = graph.volume().unwrap();
+        let expected = (4.0 / 3.0) * std::f64::consts::PI * radius.powi(3);
+        assert!(((volume - expected) / expected).abs() < 0.05);
+    }
+
+    #[test]
+    fn single_corner() {
+        let field = ScalarField::from_samples(
+            E3::new(0.0, 0.0, 0.0),
+            E3::new(1.0, 1.0, 1.0),
+            (2, 2, 2),
+            vec![-1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+        )
+        .unwrap();
+        let cubes = MarchingCubes::new(&field, 0.0);
+        assert_eq!(1, cubes.polygons::<Position<E3>>().count());
+        assert_eq!(3, cubes.vertices::<Position<E3>>().count());
+
+        assert!(ScalarField::<E3>::from_samples(
+            E3::new(0.0, 0.0, 0.0),
+            E3::new(1.0, 1.0, 1.0),
+            (2, 2, 2),
+            vec![0.0; 7],
+        )
+        .is_none());
+    }
+}
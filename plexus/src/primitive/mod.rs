@@ -81,23 +81,32 @@
 //! [`StaticArity`]: crate::StaticArity
 
 pub mod cube;
+pub mod cylinder;
 pub mod decompose;
 pub mod generate;
+pub mod grid;
+pub mod isosurface;
+pub mod parametric;
+pub mod platonic;
 pub mod sphere;
+pub mod sweep;
+pub mod torus;
+pub mod triangulate;
 
 use arrayvec::ArrayVec;
 use decorum::Real;
 use itertools::izip;
 use itertools::structs::Zip as OuterZip; // Avoid collision with `Zip`.
-use num::{Integer, One, Signed, Unsigned, Zero};
+use num::{Integer, NumCast, One, Signed, ToPrimitive, Unsigned, Zero};
 use smallvec::{smallvec, SmallVec};
 use std::array;
+use std::cmp::Ordering;
 use std::convert::TryInto;
 use std::fmt::{self, Debug, Formatter};
 use std::marker::PhantomData;
-use std::ops::{Index, IndexMut};
+use std::ops::{Index, IndexMut, Mul, Sub};
 use theon::adjunct::{Adjunct, Converged, Extend, Fold, FromItems, IntoItems, Map, ZipMap};
-use theon::ops::Cross;
+use theon::ops::{Cross, Dot};
 use theon::query::{Intersection, Line, LineLine, LinePlane, Plane, Unit};
 use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector, VectorSpace};
 use theon::{AsPosition, AsPositionMut, Position};
@@ -180,13 +189,16 @@ pub trait Topological:
 
     /// Embeds an $n$-gon from $\Reals^2$ into $\Reals^3$.
     ///
-    /// The $n$-gon is rotated into the given plane about the origin.
+    /// The $n$-gon is rotated about the origin such that the $z$-axis is
+    /// aligned with the normal of the given plane and is then translated to
+    /// the origin of the plane. This is the inverse of
+    /// [`project_from_plane`].
     ///
     /// # Examples
     ///
     /// Embedding a triangle into the $xy$-plane at $z=0$:
     ///
-    /// ```rust,no_run
+    /// ```rust
     /// # extern crate nalgebra;
     /// # extern crate theon;
     /// #
@@ -210,6 +222,8 @@ pub trait Topological:
     ///     },
     /// );
     /// ```
+    ///
+    /// [`project_from_plane`]: crate::primitive::Topological::project_from_plane
     fn embed_into_e3_plane<P>(ngon: P, plane: Plane<Self::Vertex>) -> Self
     where
         Self::Vertex: EuclideanSpace + FiniteDimensional<N = U3>,
@@ -220,7 +234,11 @@ pub trait Topological:
         Self::embed_into_e3_plane_with(ngon, plane, |position| position)
     }
 
-    fn embed_into_e3_plane_with<P, F>(ngon: P, _: Plane<Position<Self::Vertex>>, f: F) -> Self
+    fn embed_into_e3_plane_with<P, F>(
+        ngon: P,
+        plane: Plane<Position<Self::Vertex>>,
+        mut f: F,
+    ) -> Self
     where
         Self::Vertex: AsPosition,
         Position<Self::Vertex>: EuclideanSpace + FiniteDimensional<N = U3>,
@@ -229,9 +247,73 @@ pub trait Topological:
         Vector<P::Vertex>: VectorSpace<Scalar = Scalar<Position<Self::Vertex>>>,
         F: FnMut(Position<Self::Vertex>) -> Self::Vertex,
     {
-        // TODO: Rotate the embedded n-gon into the plane about the origin.
-        let _ = Self::embed_into_e3_xy_with(ngon, Zero::zero(), f);
-        unimplemented!()
+        let (u, v) = plane_basis::<Position<Self::Vertex>>(&plane.normal);
+        let (ox, oy, oz) = plane.origin.into_xyz();
+        ngon.map(move |position| {
+            let (x, y) = position.into_xy();
+            f(Position::<Self::Vertex>::from_xyz(
+                ox + (u.0 * x) + (v.0 * y),
+                oy + (u.1 * x) + (v.1 * y),
+                oz + (u.2 * x) + (v.2 * y),
+            ))
+        })
+    }
+
+    /// Projects an $n$-gon from $\Reals^3$ into $\Reals^2$.
+    ///
+    /// The positions in each vertex of the $n$-gon are projected along the
+    /// normal of the given plane and expressed in coordinates of the plane
+    /// relative to its origin. This is the inverse of [`embed_into_e3_plane`]
+    /// for $n$-gons that lie in the plane and can be used to apply algorithms
+    /// in $\Reals^2$ to $n$-gons in $\Reals^3$.
+    ///
+    /// # Examples
+    ///
+    /// Projecting a triangle in the $yz$-plane:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate theon;
+    /// #
+    /// use nalgebra::{Point2, Point3};
+    /// use plexus::geometry::{Plane, Unit};
+    /// use plexus::primitive::{Topological, Trigon};
+    /// use theon::space::{Basis, EuclideanSpace};
+    ///
+    /// type E2 = Point2<f64>;
+    /// type E3 = Point3<f64>;
+    ///
+    /// let trigon = Trigon::new(
+    ///     E3::from_xyz(1.0, 0.0, 0.0),
+    ///     E3::from_xyz(1.0, 1.0, 0.0),
+    ///     E3::from_xyz(1.0, 0.0, 1.0),
+    /// );
+    /// let trigon: Trigon<E2> = trigon.project_from_plane(Plane::<E3> {
+    ///     origin: EuclideanSpace::origin(),
+    ///     normal: Unit::x(),
+    /// });
+    /// ```
+    ///
+    /// [`embed_into_e3_plane`]: crate::primitive::Topological::embed_into_e3_plane
+    #[must_use]
+    fn project_from_plane<T>(self, plane: Plane<Position<Self::Vertex>>) -> <Self as Map<T>>::Output
+    where
+        Self: Map<T>,
+        Self::Vertex: AsPosition,
+        Position<Self::Vertex>: EuclideanSpace + FiniteDimensional<N = U3>,
+        T: EuclideanSpace + FiniteDimensional<N = U2>,
+        Vector<T>: VectorSpace<Scalar = Scalar<Position<Self::Vertex>>>,
+    {
+        let (u, v) = plane_basis::<Position<Self::Vertex>>(&plane.normal);
+        let (ox, oy, oz) = plane.origin.into_xyz();
+        self.map(move |vertex| {
+            let (x, y, z) = vertex.as_position().into_xyz();
+            let (x, y, z) = (x - ox, y - oy, z - oz);
+            T::from_xy(
+                (x * u.0) + (y * u.1) + (z * u.2),
+                (x * v.0) + (y * v.1) + (z * v.2),
+            )
+        })
     }
 
     /// Projects an $n$-gon into a plane.
@@ -311,6 +393,231 @@ pub trait Polygonal: Topological {
     }
 }
 
+/// Winding of a polygon.
+///
+/// The winding of a polygon in $\Reals^3$ is relative to a direction, and is
+/// counter-clockwise if the polygon is wound counter-clockwise when viewed
+/// from the direction (i.e., looking against it).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// Geometric queries of [`Polygonal`] types in $\Reals^2$.
+///
+/// These queries treat the vertices of a polygon as a closed sequence of line
+/// segments, which need not form a simple polygon.
+///
+/// # Examples
+///
+/// Rejecting degenerate polygons in an iterator expression:
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point2;
+/// use plexus::prelude::*;
+/// use plexus::primitive::Trigon;
+///
+/// type E2 = Point2<f64>;
+///
+/// let trigons = vec![
+///     Trigon::new(E2::new(0.0, 0.0), E2::new(1.0, 0.0), E2::new(0.0, 1.0)),
+///     Trigon::new(E2::new(0.0, 0.0), E2::new(1.0, 0.0), E2::new(2.0, 0.0)),
+/// ];
+/// let trigons = trigons
+///     .into_iter()
+///     .filter(|trigon| trigon.winding().is_some())
+///     .collect::<Vec<_>>();
+/// assert_eq!(1, trigons.len());
+/// ```
+///
+/// [`Polygonal`]: crate::primitive::Polygonal
+pub trait PolygonalE2: Polygonal
+where
+    Self::Vertex: AsPosition,
+    Position<Self::Vertex>: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    /// Gets the signed area of the polygon.
+    ///
+    /// The area is positive if the polygon is wound counter-clockwise and
+    /// negative if it is wound clockwise.
+    fn signed_area(&self) -> Scalar<Position<Self::Vertex>> {
+        let two = <Scalar<Position<Self::Vertex>> as One>::one() + One::one();
+        shoelace::<Position<Self::Vertex>>(&points_e2(self)) / two
+    }
+
+    /// Gets the winding of the polygon.
+    ///
+    /// Returns `None` if the polygon has no area.
+    fn winding(&self) -> Option<Winding> {
+        let area = self.signed_area();
+        if area > Zero::zero() {
+            Some(Winding::CounterClockwise)
+        }
+        else if area < Zero::zero() {
+            Some(Winding::Clockwise)
+        }
+        else {
+            None
+        }
+    }
+
+    /// Gets the centroid of the area of the polygon.
+    ///
+    /// Returns `None` if the polygon has no area.
+    fn centroid(&self) -> Option<Position<Self::Vertex>> {
+        centroid_e2::<Position<Self::Vertex>>(&points_e2(self))
+            .map(|(x, y)| Position::<Self::Vertex>::from_xy(x, y))
+    }
+
+    /// Determines if any edges of the polygon intersect other than adjacent
+    /// edges at their shared vertex.
+    fn is_self_intersecting(&self) -> bool {
+        is_self_intersecting_e2::<Position<Self::Vertex>>(&points_e2(self))
+    }
+
+    /// Determines if a point is within or on the boundary of the polygon.
+    ///
+    /// Self-intersecting polygons use the even-odd rule.
+    fn contains_point(&self, point: &Position<Self::Vertex>) -> bool {
+        contains_point_e2::<Position<Self::Vertex>>(&points_e2(self), point.into_xy())
+    }
+}
+
+impl<P> PolygonalE2 for P
+where
+    P: Polygonal,
+    P::Vertex: AsPosition,
+    Position<P::Vertex>: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+}
+
+/// Geometric queries of [`Polygonal`] types in $\Reals^3$.
+///
+/// The normal of a polygon is computed using Newell's method, which is robust
+/// for polygons that are concave or not quite planar. Queries that are only
+/// meaningful in $\Reals^2$ project the polygon along the basis axis that is
+/// most parallel to its normal, so they assume that the polygon is planar.
+///
+/// [`Polygonal`]: crate::primitive::Polygonal
+pub trait PolygonalE3: Polygonal
+where
+    Self::Vertex: AsPosition,
+    Position<Self::Vertex>: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    /// Gets the vector area of the polygon.
+    ///
+    /// The magnitude of the vector area is the area of the polygon and its
+    /// direction is the normal of the polygon as given by the right-hand rule.
+    fn vector_area(&self) -> Vector<Position<Self::Vertex>> {
+        let (x, y, z) = newell::<Position<Self::Vertex>>(&points_e3(self));
+        let two = <Scalar<Position<Self::Vertex>> as One>::one() + One::one();
+        Position::<Self::Vertex>::from_xyz(x / two, y / two, z / two)
+            - Position::<Self::Vertex>::origin()
+    }
+
+    /// Gets the normal of the polygon.
+    ///
+    /// Returns `None` if the polygon has no area.
+    fn normal(&self) -> Option<Unit<Vector<Position<Self::Vertex>>>> {
+        Unit::try_from_inner(self.vector_area())
+    }
+
+    /// Gets the winding of the polygon relative to a direction.
+    ///
+    /// Returns `None` if the polygon has no area or is perpendicular to the
+    /// direction.
+    fn winding_about(&self, direction: Vector<Position<Self::Vertex>>) -> Option<Winding> {
+        let (x, y, z) = self.vector_area().into_xyz();
+        let (dx, dy, dz) = direction.into_xyz();
+        let projection = (x * dx) + (y * dy) + (z * dz);
+        if projection > Zero::zero() {
+            Some(Winding::CounterClockwise)
+        }
+        else if projection < Zero::zero() {
+            Some(Winding::Clockwise)
+        }
+        else {
+            None
+        }
+    }
+
+    /// Gets the centroid of the area of the polygon.
+    ///
+    /// Returns `None` if the polygon has no area.
+    fn centroid(&self) -> Option<Position<Self::Vertex>> {
+        let points = points_e3(self);
+        let normal = newell::<Position<Self::Vertex>>(&points);
+        centroid_e3::<Position<Self::Vertex>>(&points, normal)
+            .map(|(x, y, z)| Position::<Self::Vertex>::from_xyz(x, y, z))
+    }
+
+    /// Determines if all vertices of the polygon are within a distance of
+    /// `epsilon` from the plane of the polygon.
+    ///
+    /// The plane of the polygon passes through the mean of its vertices and is
+    /// perpendicular to its normal. Returns `false` if the polygon has no
+    /// area.
+    fn is_planar(&self, epsilon: Scalar<Position<Self::Vertex>>) -> bool {
+        let points = points_e3(self);
+        let (nx, ny, nz) = newell::<Position<Self::Vertex>>(&points);
+        let magnitude = Real::sqrt((nx * nx) + (ny * ny) + (nz * nz));
+        if magnitude.is_zero() {
+            return false;
+        }
+        let zero = <Scalar<Position<Self::Vertex>> as Zero>::zero();
+        let (n, mx, my, mz) = points.iter().fold(
+            (zero, zero, zero, zero),
+            |(n, mx, my, mz), (x, y, z)| (n + One::one(), mx + *x, my + *y, mz + *z),
+        );
+        let (mx, my, mz) = (mx / n, my / n, mz / n);
+        points.iter().all(|(x, y, z)| {
+            let distance = ((*x - mx) * nx) + ((*y - my) * ny) + ((*z - mz) * nz);
+            Real::abs(distance) <= epsilon * magnitude
+        })
+    }
+
+    /// Determines if any edges of the polygon intersect other than adjacent
+    /// edges at their shared vertex.
+    fn is_self_intersecting(&self) -> bool {
+        let points = points_e3(self);
+        let normal = newell::<Position<Self::Vertex>>(&points);
+        let points = points
+            .into_iter()
+            .map(|point| project_e3::<Position<Self::Vertex>>(point, normal))
+            .collect::<Vec<_>>();
+        is_self_intersecting_e2::<Position<Self::Vertex>>(&points)
+    }
+
+    /// Determines if a point is within or on the boundary of the polygon.
+    ///
+    /// The point is projected into the plane of the polygon. Self-intersecting
+    /// polygons use the even-odd rule.
+    fn contains_point(&self, point: &Position<Self::Vertex>) -> bool {
+        let points = points_e3(self);
+        let normal = newell::<Position<Self::Vertex>>(&points);
+        let points = points
+            .into_iter()
+            .map(|point| project_e3::<Position<Self::Vertex>>(point, normal))
+            .collect::<Vec<_>>();
+        contains_point_e2::<Position<Self::Vertex>>(
+            &points,
+            project_e3::<Position<Self::Vertex>>(point.into_xyz(), normal),
+        )
+    }
+}
+
+impl<P> PolygonalE3 for P
+where
+    P: Polygonal,
+    P::Vertex: AsPosition,
+    Position<P::Vertex>: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+}
+
 pub trait IntoIndexed<N>: Polygonal
 where
     N: Copy + Integer + Unsigned,
@@ -814,6 +1121,230 @@ impl<G> Trigon<G> {
     }
 }
 
+/// Intersection of triangles.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum TrigonTrigon<S>
+where
+    S: EuclideanSpace,
+{
+    /// The triangles touch at a point.
+    Point(S),
+    /// The triangles intersect along a segment.
+    Edge(Edge<S>),
+    /// The triangles are coplanar and overlap.
+    Coplanar,
+}
+
+impl<S> TrigonTrigon<S>
+where
+    S: EuclideanSpace,
+{
+    pub fn into_point(self) -> Option<S> {
+        match self {
+            TrigonTrigon::Point(point) => Some(point),
+            _ => None,
+        }
+    }
+
+    pub fn into_edge(self) -> Option<Edge<S>> {
+        match self {
+            TrigonTrigon::Edge(edge) => Some(edge),
+            _ => None,
+        }
+    }
+}
+
+impl<S> Debug for TrigonTrigon<S>
+where
+    S: Debug + EuclideanSpace,
+    Vector<S>: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            TrigonTrigon::Point(point) => write!(formatter, "Point({:?})", point),
+            TrigonTrigon::Edge(edge) => write!(formatter, "Edge({:?})", edge),
+            TrigonTrigon::Coplanar => write!(formatter, "Coplanar"),
+        }
+    }
+}
+
+impl<T> Intersection<Trigon<T>> for Trigon<T>
+where
+    T: AsPosition,
+    Position<T>: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<Position<T>>: Cross<Output = Vector<Position<T>>>,
+{
+    type Output = TrigonTrigon<Position<T>>;
+
+    fn intersection(&self, other: &Trigon<T>) -> Option<Self::Output> {
+        intersect_triangles(
+            self.positions().cloned().into_array(),
+            other.positions().cloned().into_array(),
+        )
+    }
+}
+
+// Intersects triangles using the interval overlap method described by Möller in
+// _A Fast Triangle-Triangle Intersection Test_. Degenerate triangles never
+// intersect.
+pub(crate) fn intersect_triangles<S>(p: [S; 3], q: [S; 3]) -> Option<TrigonTrigon<S>>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    let np = (p[1] - p[0]).cross(p[2] - p[0]);
+    let nq = (q[1] - q[0]).cross(q[2] - q[0]);
+    if np.is_zero() || nq.is_zero() {
+        return None;
+    }
+    // Signed distances (scaled by the magnitude of the normals) of the vertices
+    // of each triangle from the plane of the other triangle.
+    let dp = p.map(|point| nq.dot(point - q[0]));
+    let dq = q.map(|point| np.dot(point - p[0]));
+    let is_separated = |d: &[Scalar<S>; 3]| {
+        d.iter().all(|d| *d > Zero::zero()) || d.iter().all(|d| *d < Zero::zero())
+    };
+    if is_separated(&dp) || is_separated(&dq) {
+        return None;
+    }
+    if dp.iter().all(|d| d.is_zero()) {
+        return is_coplanar_overlap(&p, &q, np).then_some(TrigonTrigon::Coplanar);
+    }
+    // Each triangle crosses the plane of the other along a segment on the line
+    // of intersection of the planes. The triangles intersect where these
+    // segments overlap.
+    let direction = np.cross(nq);
+    let t = |point: S| direction.dot(point - p[0]);
+    let order = |(a, b): (S, S)| if t(a) <= t(b) { (a, b) } else { (b, a) };
+    let (a0, a1) = order(plane_crossing(&p, &dp));
+    let (b0, b1) = order(plane_crossing(&q, &dq));
+    let start = if t(a0) >= t(b0) { a0 } else { b0 };
+    let end = if t(a1) <= t(b1) { a1 } else { b1 };
+    match t(start).partial_cmp(&t(end)) {
+        Some(Ordering::Less) => Some(TrigonTrigon::Edge(Edge::new(start, end))),
+        Some(Ordering::Equal) => Some(TrigonTrigon::Point(start)),
+        _ => None,
+    }
+}
+
+// Gets the segment along which a triangle crosses a plane given the signed
+// distances of its vertices from that plane. The triangle must not be
+// separated from the plane.
+fn plane_crossing<S>(points: &[S; 3], distances: &[Scalar<S>; 3]) -> (S, S)
+where
+    S: EuclideanSpace,
+{
+    let zero = Scalar::<S>::zero();
+    let crossings = points
+        .iter()
+        .zip(distances.iter())
+        .perimeter()
+        .flat_map(|((a, da), (b, db))| {
+            if da.is_zero() {
+                Some(*a)
+            }
+            else if !db.is_zero() && ((*da < zero) != (*db < zero)) {
+                Some(*a + ((*b - *a) * (*da / (*da - *db))))
+            }
+            else {
+                None
+            }
+        })
+        .collect::<SmallVec<[_; 3]>>();
+    let first = *crossings.first().expect("triangle separated from plane");
+    let last = *crossings.last().expect("triangle separated from plane");
+    (first, last)
+}
+
+// Determines if coplanar triangles overlap by projecting them onto the axis
+// plane in which they have the greatest area.
+fn is_coplanar_overlap<S>(p: &[S; 3], q: &[S; 3], normal: Vector<S>) -> bool
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let abs = |x: Scalar<S>| if x < Zero::zero() { -x } else { x };
+    let (x, y, z) = normal.into_xyz();
+    let (x, y, z) = (abs(x), abs(y), abs(z));
+    let project = |point: &S| {
+        let (px, py, pz) = point.into_xyz();
+        if z >= x && z >= y {
+            (px, py)
+        }
+        else if y >= x {
+            (pz, px)
+        }
+        else {
+            (py, pz)
+        }
+    };
+    let p = p.map(|point| project(&point));
+    let q = q.map(|point| project(&point));
+    p.iter()
+        .perimeter()
+        .any(|(a, b)| q.iter().perimeter().any(|(c, d)| is_segment_overlap(*a, *b, *c, *d)))
+        || is_point_in_triangle(q[0], &p)
+        || is_point_in_triangle(p[0], &q)
+}
+
+// Gets twice the signed area of a triangle in two dimensions. The area is
+// positive if the triangle is wound counter-clockwise.
+fn orientation<T>(a: (T, T), b: (T, T), c: (T, T)) -> T
+where
+    T: Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    ((b.0 - a.0) * (c.1 - a.1)) - ((b.1 - a.1) * (c.0 - a.0))
+}
+
+// Determines if closed segments in two dimensions overlap, including segments
+// that touch at endpoints and collinear segments.
+fn is_segment_overlap<T>(a: (T, T), b: (T, T), c: (T, T), d: (T, T)) -> bool
+where
+    T: Copy + Mul<Output = T> + PartialOrd + Sub<Output = T> + Zero,
+{
+    let zero = T::zero();
+    let sign = |x: T| {
+        if x > zero {
+            1
+        }
+        else if x < zero {
+            -1
+        }
+        else {
+            0
+        }
+    };
+    let within = |a: (T, T), b: (T, T), p: (T, T)| {
+        let (lx, ux) = if a.0 <= b.0 { (a.0, b.0) } else { (b.0, a.0) };
+        let (ly, uy) = if a.1 <= b.1 { (a.1, b.1) } else { (b.1, a.1) };
+        p.0 >= lx && p.0 <= ux && p.1 >= ly && p.1 <= uy
+    };
+    let (o1, o2) = (sign(orientation(a, b, c)), sign(orientation(a, b, d)));
+    let (o3, o4) = (sign(orientation(c, d, a)), sign(orientation(c, d, b)));
+    if o1 * o2 < 0 && o3 * o4 < 0 {
+        return true;
+    }
+    (o1 == 0 && within(a, b, c))
+        || (o2 == 0 && within(a, b, d))
+        || (o3 == 0 && within(c, d, a))
+        || (o4 == 0 && within(c, d, b))
+}
+
+// Determines if a point in two dimensions is within or on the boundary of a
+// triangle of either winding.
+fn is_point_in_triangle<T>(point: (T, T), triangle: &[(T, T); 3]) -> bool
+where
+    T: Copy + Mul<Output = T> + PartialOrd + Sub<Output = T> + Zero,
+{
+    let zero = T::zero();
+    let [a, b, c] = *triangle;
+    let orientations = [
+        orientation(a, b, point),
+        orientation(b, c, point),
+        orientation(c, a, point),
+    ];
+    orientations.iter().all(|x| *x >= zero) || orientations.iter().all(|x| *x <= zero)
+}
+
 impl<G> Rotate for Trigon<G> {
     fn rotate(self, n: isize) -> Self {
         let n = umod(n, Self::ARITY as isize);
@@ -1309,6 +1840,232 @@ where
         })
 }
 
+// Gets the orthonormal basis of a plane that is the image of the $x$- and
+// $y$-axes under the rotation about the origin that takes the $z$-axis to the
+// normal of the plane. The rotation is the smallest such rotation, except when
+// the normal is opposite the $z$-axis, in which case it is a half turn about
+// the $x$-axis.
+fn plane_basis<S>(normal: &Unit<Vector<S>>) -> (Xyz<S>, Xyz<S>)
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let zero = Scalar::<S>::zero();
+    let one = Scalar::<S>::one();
+    let (a, b, c) = normal.get().into_xyz();
+    if c + one <= zero {
+        ((one, zero, zero), (zero, -one, zero))
+    }
+    else {
+        let k = one / (one + c);
+        let ab = a * b * k;
+        ((one - (a * a * k), -ab, -a), (-ab, one - (b * b * k), -b))
+    }
+}
+
+type Xy<S> = (Scalar<S>, Scalar<S>);
+type Xyz<S> = (Scalar<S>, Scalar<S>, Scalar<S>);
+
+fn points_e2<P>(polygon: &P) -> Vec<Xy<Position<P::Vertex>>>
+where
+    P: Polygonal,
+    P::Vertex: AsPosition,
+    Position<P::Vertex>: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    polygon
+        .as_ref()
+        .iter()
+        .map(|vertex| vertex.as_position().into_xy())
+        .collect()
+}
+
+fn points_e3<P>(polygon: &P) -> Vec<Xyz<Position<P::Vertex>>>
+where
+    P: Polygonal,
+    P::Vertex: AsPosition,
+    Position<P::Vertex>: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    polygon
+        .as_ref()
+        .iter()
+        .map(|vertex| vertex.as_position().into_xyz())
+        .collect()
+}
+
+// Gets twice the signed area of a polygon in two dimensions using the shoelace
+// formula. The area is positive if the polygon is wound counter-clockwise.
+fn shoelace<S>(points: &[Xy<S>]) -> Scalar<S>
+where
+    S: EuclideanSpace,
+{
+    let origin = (Zero::zero(), Zero::zero());
+    points
+        .iter()
+        .perimeter()
+        .fold(Zero::zero(), |area, (a, b)| area + orientation(origin, *a, *b))
+}
+
+fn centroid_e2<S>(points: &[Xy<S>]) -> Option<Xy<S>>
+where
+    S: EuclideanSpace,
+{
+    let area = shoelace::<S>(points);
+    if area.is_zero() {
+        return None;
+    }
+    let origin = (Zero::zero(), Zero::zero());
+    let (x, y) = points.iter().perimeter().fold(
+        (Zero::zero(), Zero::zero()),
+        |(x, y): Xy<S>, (a, b)| {
+            let cross = orientation(origin, *a, *b);
+            (x + ((a.0 + b.0) * cross), y + ((a.1 + b.1) * cross))
+        },
+    );
+    let three = Scalar::<S>::one() + One::one() + One::one();
+    Some((x / (area * three), y / (area * three)))
+}
+
+// Determines if edges intersect other than adjacent edges at their shared
+// vertex. Adjacent edges intersect if they are collinear and fold back onto
+// each other.
+fn is_self_intersecting_e2<S>(points: &[Xy<S>]) -> bool
+where
+    S: EuclideanSpace,
+{
+    let zero = Scalar::<S>::zero();
+    let n = points.len();
+    let edge = |i: usize| (points[i], points[(i + 1) % n]);
+    (0..n).any(|i| {
+        let (a, b) = edge(i);
+        let c = points[(i + 2) % n];
+        let is_folded = orientation(a, b, c) == zero
+            && (((b.0 - a.0) * (c.0 - b.0)) + ((b.1 - a.1) * (c.1 - b.1))) < zero;
+        is_folded
+            || ((i + 2)..n)
+                .filter(|j| (j + 1) % n != i)
+                .any(|j| {
+                    let (c, d) = edge(j);
+                    is_segment_overlap(a, b, c, d)
+                })
+    })
+}
+
+// Determines if a point is within or on the boundary of a polygon using the
+// even-odd rule.
+fn contains_point_e2<S>(points: &[Xy<S>], point: Xy<S>) -> bool
+where
+    S: EuclideanSpace,
+{
+    let zero = Scalar::<S>::zero();
+    let mut is_inside = false;
+    for (a, b) in points.iter().perimeter() {
+        if is_segment_overlap(*a, *b, point, point) {
+            return true;
+        }
+        // Count crossings of a ray cast from the point in the positive $x$
+        // direction. Each edge includes its lower endpoint but not its upper
+        // endpoint, so vertices on the ray are counted once.
+        if (a.1 > point.1) != (b.1 > point.1) {
+            let side = orientation(*a, *b, point);
+            if (b.1 > a.1) == (side > zero) {
+                is_inside = !is_inside;
+            }
+        }
+    }
+    is_inside
+}
+
+// Gets the vector area of a polygon in three dimensions scaled by two using
+// Newell's method.
+fn newell<S>(points: &[Xyz<S>]) -> Xyz<S>
+where
+    S: EuclideanSpace,
+{
+    points.iter().perimeter().fold(
+        (Zero::zero(), Zero::zero(), Zero::zero()),
+        |(x, y, z): Xyz<S>, (a, b)| {
+            (
+                x + ((a.1 - b.1) * (a.2 + b.2)),
+                y + ((a.2 - b.2) * (a.0 + b.0)),
+                z + ((a.0 - b.0) * (a.1 + b.1)),
+            )
+        },
+    )
+}
+
+// Gets the centroid of the area of a planar polygon in three dimensions by
+// weighting the centroids of the triangles in a fan by their signed areas
+// along the normal.
+fn centroid_e3<S>(points: &[Xyz<S>], normal: Xyz<S>) -> Option<Xyz<S>>
+where
+    S: EuclideanSpace,
+{
+    let (nx, ny, nz) = normal;
+    let zero = Scalar::<S>::zero();
+    let o = *points.first()?;
+    let (area, x, y, z) = points.iter().skip(1).zip(points.iter().skip(2)).fold(
+        (zero, zero, zero, zero),
+        |(area, x, y, z), (a, b)| {
+            let (u, v) = ((a.0 - o.0, a.1 - o.1, a.2 - o.2), (b.0 - o.0, b.1 - o.1, b.2 - o.2));
+            let weight = (((u.1 * v.2) - (u.2 * v.1)) * nx)
+                + (((u.2 * v.0) - (u.0 * v.2)) * ny)
+                + (((u.0 * v.1) - (u.1 * v.0)) * nz);
+            (
+                area + weight,
+                x + ((o.0 + a.0 + b.0) * weight),
+                y + ((o.1 + a.1 + b.1) * weight),
+                z + ((o.2 + a.2 + b.2) * weight),
+            )
+        },
+    );
+    if area.is_zero() {
+        return None;
+    }
+    let three = Scalar::<S>::one() + One::one() + One::one();
+    Some((x / (area * three), y / (area * three), z / (area * three)))
+}
+
+// Projects a point along the basis axis that is most parallel to a normal. The
+// remaining axes are ordered such that a polygon that is wound
+// counter-clockwise about the normal remains wound counter-clockwise.
+fn project_e3<S>(point: Xyz<S>, normal: Xyz<S>) -> Xy<S>
+where
+    S: EuclideanSpace,
+{
+    let (x, y, z) = point;
+    let (nx, ny, nz) = normal;
+    let (ax, ay, az) = (Real::abs(nx), Real::abs(ny), Real::abs(nz));
+    if az >= ax && az >= ay {
+        if nz >= Zero::zero() {
+            (x, y)
+        }
+        else {
+            (y, x)
+        }
+    }
+    else if ay >= ax {
+        if ny >= Zero::zero() {
+            (z, x)
+        }
+        else {
+            (x, z)
+        }
+    }
+    else if nx >= Zero::zero() {
+        (y, z)
+    }
+    else {
+        (z, y)
+    }
+}
+
+pub(in crate::primitive) fn into_scalar<T, S>(value: T) -> Scalar<S>
+where
+    T: ToPrimitive,
+    S: EuclideanSpace,
+{
+    <Scalar<S> as NumCast>::from(value).unwrap()
+}
+
 fn umod<T>(n: T, m: T) -> T
 where
     T: Copy + Integer,
@@ -1318,13 +2075,18 @@ where
 
 #[cfg(test)]
 mod tests {
-    use nalgebra::Point2;
+    use nalgebra::{Point2, Point3, Vector3};
     use theon::adjunct::Converged;
+    use theon::query::{Intersection, Plane, Unit};
     use theon::space::EuclideanSpace;
 
-    use crate::primitive::{NGon, Polygonal, Tetragon, Trigon};
+    use crate::primitive::{
+        NGon, Polygonal, PolygonalE2, PolygonalE3, Tetragon, Topological, Trigon, TrigonTrigon,
+        Winding,
+    };
 
     type E2 = Point2<f64>;
+    type E3 = Point3<f64>;
 
     #[test]
     fn convexity() {
@@ -1367,4 +2129,201 @@ mod tests {
         ]);
         assert!(!pentagon.is_convex());
     }
+
+    #[test]
+    fn trigon_intersection() {
+        let trigon = Trigon::new(
+            E3::new(0.0, 0.0, 0.0),
+            E3::new(2.0, 0.0, 0.0),
+            E3::new(0.0, 2.0, 0.0),
+        );
+
+        // Triangle that crosses the plane of the other triangle.
+        let other = Trigon::new(
+            E3::new(0.5, 0.5, -1.0),
+            E3::new(0.5, 0.5, 1.0),
+            E3::new(0.5, -1.0, 0.0),
+        );
+        let edge = trigon.intersection(&other).unwrap().into_edge().unwrap();
+        let [a, b] = edge.into_array();
+        let (a, b) = if a.y <= b.y { (a, b) } else { (b, a) };
+        assert!((a - E3::new(0.5, 0.0, 0.0)).norm() < 1e-9);
+        assert!((b - E3::new(0.5, 0.5, 0.0)).norm() < 1e-9);
+        let other = Trigon::new(
+            E3::new(0.5, 0.5, 4.0),
+            E3::new(0.5, 0.5, 6.0),
+            E3::new(0.5, -1.0, 5.0),
+        );
+        assert!(trigon.intersection(&other).is_none());
+
+        // Coplanar triangles.
+        let other = Trigon::new(
+            E3::new(0.5, 0.5, 0.0),
+            E3::new(3.0, 0.5, 0.0),
+            E3::new(0.5, 3.0, 0.0),
+        );
+        assert_eq!(Some(TrigonTrigon::Coplanar), trigon.intersection(&other));
+        let other = Trigon::new(
+            E3::new(5.0, 5.0, 0.0),
+            E3::new(6.0, 5.0, 0.0),
+            E3::new(5.0, 6.0, 0.0),
+        );
+        assert!(trigon.intersection(&other).is_none());
+    }
+
+    #[test]
+    fn area_and_winding() {
+        let square = Tetragon::new(
+            E2::from_xy(0.0, 0.0),
+            E2::from_xy(2.0, 0.0),
+            E2::from_xy(2.0, 2.0),
+            E2::from_xy(0.0, 2.0),
+        );
+        assert_eq!(4.0, square.signed_area());
+        assert_eq!(Some(Winding::CounterClockwise), square.winding());
+
+        let [a, b, c, d] = square.into_array();
+        let square = Tetragon::new(d, c, b, a);
+        assert_eq!(-4.0, square.signed_area());
+        assert_eq!(Some(Winding::Clockwise), square.winding());
+
+        // Degenerate triangle.
+        let trigon = Trigon::new(
+            E2::from_xy(0.0, 0.0),
+            E2::from_xy(1.0, 0.0),
+            E2::from_xy(2.0, 0.0),
+        );
+        assert_eq!(None, trigon.winding());
+        assert_eq!(None, trigon.centroid());
+    }
+
+    #[test]
+    fn centroid() {
+        let square = Tetragon::new(
+            E2::from_xy(0.0, 0.0),
+            E2::from_xy(2.0, 0.0),
+            E2::from_xy(2.0, 2.0),
+            E2::from_xy(0.0, 2.0),
+        );
+        assert_eq!(Some(E2::from_xy(1.0, 1.0)), square.centroid());
+
+        let square = Tetragon::new(
+            E3::from_xyz(1.0, 0.0, 0.0),
+            E3::from_xyz(1.0, 2.0, 0.0),
+            E3::from_xyz(1.0, 2.0, 2.0),
+            E3::from_xyz(1.0, 0.0, 2.0),
+        );
+        assert_eq!(Some(E3::from_xyz(1.0, 1.0, 1.0)), square.centroid());
+    }
+
+    #[test]
+    fn normal_and_planarity() {
+        let square = Tetragon::new(
+            E3::from_xyz(0.0, 0.0, 0.0),
+            E3::from_xyz(1.0, 0.0, 0.0),
+            E3::from_xyz(1.0, 1.0, 0.0),
+            E3::from_xyz(0.0, 1.0, 0.0),
+        );
+        assert_eq!(Vector3::z(), square.normal().unwrap().into_inner());
+        assert_eq!(Some(Winding::CounterClockwise), square.winding_about(Vector3::z()));
+        assert_eq!(Some(Winding::Clockwise), square.winding_about(-Vector3::z()));
+        assert_eq!(None, square.winding_about(Vector3::x()));
+        assert!(square.is_planar(0.0));
+
+        // Warped quadrilateral.
+        let tetragon = Tetragon::new(
+            E3::from_xyz(0.0, 0.0, 0.0),
+            E3::from_xyz(1.0, 0.0, 0.0),
+            E3::from_xyz(1.0, 1.0, 0.1),
+            E3::from_xyz(0.0, 1.0, 0.0),
+        );
+        assert!(tetragon.is_planar(0.1));
+        assert!(!tetragon.is_planar(0.01));
+    }
+
+    #[test]
+    fn self_intersection() {
+        let square = Tetragon::new(
+            E2::from_xy(0.0, 0.0),
+            E2::from_xy(1.0, 0.0),
+            E2::from_xy(1.0, 1.0),
+            E2::from_xy(0.0, 1.0),
+        );
+        assert!(!square.is_self_intersecting());
+
+        // Bowtie.
+        let tetragon = Tetragon::new(
+            E2::from_xy(0.0, 0.0),
+            E2::from_xy(1.0, 1.0),
+            E2::from_xy(1.0, 0.0),
+            E2::from_xy(0.0, 1.0),
+        );
+        assert!(tetragon.is_self_intersecting());
+
+        // Bowtie in the $yz$-plane.
+        let tetragon = Tetragon::new(
+            E3::from_xyz(1.0, 0.0, 0.0),
+            E3::from_xyz(1.0, 2.0, 2.0),
+            E3::from_xyz(1.0, 2.0, 0.0),
+            E3::from_xyz(1.0, 0.0, 1.0),
+        );
+        assert!(tetragon.is_self_intersecting());
+    }
+
+    #[test]
+    fn contains_point() {
+        let square = Tetragon::new(
+            E2::from_xy(0.0, 0.0),
+            E2::from_xy(2.0, 0.0),
+            E2::from_xy(2.0, 2.0),
+            E2::from_xy(0.0, 2.0),
+        );
+        assert!(square.contains_point(&E2::from_xy(1.0, 1.0)));
+        assert!(square.contains_point(&E2::from_xy(2.0, 1.0)));
+        assert!(square.contains_point(&E2::from_xy(0.0, 0.0)));
+        assert!(!square.contains_point(&E2::from_xy(3.0, 1.0)));
+        assert!(!square.contains_point(&E2::from_xy(-1.0, 2.0)));
+
+        let square = Tetragon::new(
+            E3::from_xyz(1.0, 0.0, 0.0),
+            E3::from_xyz(1.0, 2.0, 0.0),
+            E3::from_xyz(1.0, 2.0, 2.0),
+            E3::from_xyz(1.0, 0.0, 2.0),
+        );
+        assert!(square.contains_point(&E3::from_xyz(1.0, 1.0, 1.0)));
+        assert!(!square.contains_point(&E3::from_xyz(1.0, 3.0, 1.0)));
+    }
+
+    #[test]
+    fn embed_into_and_project_from_plane() {
+        let trigon = Trigon::new(
+            E2::from_xy(-1.0, 0.0),
+            E2::from_xy(1.0, 0.0),
+            E2::from_xy(0.0, 2.0),
+        );
+        for normal in [
+            Vector3::new(1.0, 1.0, 1.0),
+            Vector3::new(0.0, -1.0, 0.0),
+            Vector3::new(0.0, 0.0, -1.0),
+        ] {
+            let plane = Plane::<E3> {
+                origin: E3::new(1.0, 2.0, 3.0),
+                normal: Unit::try_from_inner(normal).unwrap(),
+            };
+            let embedding = Trigon::<E3>::embed_into_e3_plane(trigon, plane);
+            // The embedding lies in the plane and preserves winding.
+            assert!(embedding
+                .as_ref()
+                .iter()
+                .all(|position| (position - plane.origin).dot(plane.normal.get()).abs() < 1e-9));
+            assert!((embedding.normal().unwrap().into_inner() - plane.normal.get()).norm() < 1e-9);
+
+            let projection: Trigon<E2> = embedding.project_from_plane(plane);
+            assert!(projection
+                .as_ref()
+                .iter()
+                .zip(trigon.as_ref())
+                .all(|(a, b)| (a - b).norm() < 1e-9));
+        }
+    }
 }
@@ -0,0 +1,377 @@
+//! Parametric surface primitives.
+//!
+//! A [`ParametricSurface`] samples a function $f(u, v)$ over a rectangular
+//! domain and connects the samples with quadrilaterals. Many primitives can be
+//! expressed this way, such as spheres, tori, and surfaces of revolution.
+//!
+//! # Examples
+//!
+//! Generating a graph from the positional data of a torus:
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use nalgebra::Point3;
+//! use plexus::graph::MeshGraph;
+//! use plexus::prelude::*;
+//! use plexus::primitive::generate::Position;
+//! use plexus::primitive::parametric::ParametricSurface;
+//! use std::f64::consts::PI;
+//!
+//! type E3 = Point3<f64>;
+//!
+//! let tau = 2.0 * PI;
+//! let torus = ParametricSurface::new(32, 16, |u: f64, v: f64| {
+//!     let r = 1.0 + (0.25 * v.cos());
+//!     E3::new(r * u.cos(), r * u.sin(), 0.25 * v.sin())
+//! })
+//! .with_domain(0.0..tau, 0.0..tau)
+//! .with_wrap(true, true);
+//! let graph = MeshGraph::<E3>::from_raw_buffers(
+//!     torus.indexing_polygons::<Position>(),
+//!     torus.vertices::<Position<E3>>(),
+//! )
+//! .unwrap();
+//! ```
+
+use num::{One, Zero};
+use std::cmp;
+use std::ops::Range;
+use theon::adjunct::Map;
+use theon::ops::Cross;
+use theon::query::Unit;
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
+use typenum::U3;
+
+use crate::primitive::generate::{
+    AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
+    IndexingPolygonGenerator, Normal, PolygonGenerator, Position,
+};
+use crate::primitive::{into_scalar, Tetragon};
+
+/// Surface defined by a function of two parameters.
+///
+/// The function is sampled at `nu + 1` values of $u$ and `nv + 1` values of
+/// $v$ evenly spaced over the domain. Polygons are quadrilaterals wound such
+/// that they face the direction of $\partial f / \partial u \times \partial f
+/// / \partial v$.
+///
+/// If a parameter wraps, then the function is assumed to be periodic over the
+/// domain of that parameter and the samples at either end of the domain are
+/// shared. For example, a torus wraps in both $u$ and $v$ and is closed. Note
+/// that the samples are shared as-is, so surfaces that join with a twist (such
+/// as a Möbius strip) should not wrap.
+///
+/// Normals are approximated with differences between neighboring samples:
+/// central differences in the interior and one-sided differences on a
+/// boundary that does not wrap.
+#[derive(Clone)]
+pub struct ParametricSurface<S, F>
+where
+    S: EuclideanSpace,
+{
+    f: F,
+    u: Range<Scalar<S>>,
+    v: Range<Scalar<S>>,
+    nu: usize,
+    nv: usize,
+    is_wrapped_u: bool,
+    is_wrapped_v: bool,
+}
+
+impl<S, F> ParametricSurface<S, F>
+where
+    S: EuclideanSpace,
+    F: Fn(Scalar<S>, Scalar<S>) -> S,
+{
+    /// Creates a surface with `nu` and `nv` segments over the unit square that
+    /// does not wrap.
+    pub fn new(nu: usize, nv: usize, f: F) -> Self {
+        ParametricSurface {
+            f,
+            u: Zero::zero()..One::one(),
+            v: Zero::zero()..One::one(),
+            nu: cmp::max(1, nu),
+            nv: cmp::max(1, nv),
+            is_wrapped_u: false,
+            is_wrapped_v: false,
+        }
+    }
+
+    /// Sets the domain of the $u$ and $v$ parameters.
+    pub fn with_domain(mut self, u: Range<Scalar<S>>, v: Range<Scalar<S>>) -> Self {
+        self.u = u;
+        self.v = v;
+        self
+    }
+
+    /// Sets whether or not the surface wraps in $u$ and $v$.
+    ///
+    /// A wrapped parameter requires at least three segments and is clamped to
+    /// that minimum.
+    pub fn with_wrap(mut self, u: bool, v: bool) -> Self {
+        self.is_wrapped_u = u;
+        self.is_wrapped_v = v;
+        if u {
+            self.nu = cmp::max(3, self.nu);
+        }
+        if v {
+            self.nv = cmp::max(3, self.nv);
+        }
+        self
+    }
+
+    fn position(&self, u: usize, v: usize) -> S {
+        let lerp = |range: &Range<Scalar<S>>, i: usize, n: usize| {
+            let t = into_scalar::<_, S>(i) / into_scalar::<_, S>(n);
+            range.start + ((range.end - range.start) * t)
+        };
+        (self.f)(lerp(&self.u, u, self.nu), lerp(&self.v, v, self.nv))
+    }
+
+    fn vertex_with_position_from(&self, index: usize) -> S {
+        let (u, v) = self.map_vertex_index(index);
+        self.position(u, v)
+    }
+
+    fn vertex_with_normal_from(&self, index: usize) -> Unit<Vector<S>>
+    where
+        S: FiniteDimensional<N = U3>,
+        Vector<S>: Cross<Output = Vector<S>>,
+    {
+        let (u, v) = self.map_vertex_index(index);
+        let (u0, u1) = neighbors(u, self.nu, self.is_wrapped_u);
+        let (v0, v1) = neighbors(v, self.nv, self.is_wrapped_v);
+        // Use the samples of an adjacent row or column if a partial derivative
+        // vanishes. This occurs where the surface converges to a point, such as
+        // at the poles of a sphere.
+        let du = |v: usize| self.position(u1, v) - self.position(u0, v);
+        let dv = |u: usize| self.position(u, v1) - self.position(u, v0);
+        Unit::try_from_inner(du(v).cross(dv(u)))
+            .or_else(|| Unit::try_from_inner(du(if v1 != v { v1 } else { v0 }).cross(dv(u))))
+            .or_else(|| Unit::try_from_inner(du(v).cross(dv(if u1 != u { u1 } else { u0 }))))
+            .expect("non-zero vector")
+    }
+
+    fn map_vertex_index(&self, index: usize) -> (usize, usize) {
+        let nu = self.column_count();
+        (index % nu, index / nu)
+    }
+}
+
+impl<S, F> ParametricSurface<S, F>
+where
+    S: EuclideanSpace,
+{
+    fn column_count(&self) -> usize {
+        if self.is_wrapped_u {
+            self.nu
+        }
+        else {
+            self.nu + 1
+        }
+    }
+
+    fn row_count(&self) -> usize {
+        if self.is_wrapped_v {
+            self.nv
+        }
+        else {
+            self.nv + 1
+        }
+    }
+
+    fn index_for_position(&self, u: usize, v: usize) -> usize {
+        ((v % self.row_count()) * self.column_count()) + (u % self.column_count())
+    }
+}
+
+impl<S, F> PolygonGenerator for ParametricSurface<S, F>
+where
+    S: EuclideanSpace,
+{
+    fn polygon_count(&self) -> usize {
+        self.nu * self.nv
+    }
+}
+
+impl<S, F> AttributeGenerator<Normal<S>> for ParametricSurface<S, F>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = ();
+}
+
+impl<S, F> AttributeVertexGenerator<Normal<S>> for ParametricSurface<S, F>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+    F: Fn(Scalar<S>, Scalar<S>) -> S,
+{
+    type Output = Unit<Vector<S>>;
+
+    fn vertex_count(&self) -> usize {
+        self.column_count() * self.row_count()
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        self.vertex_with_normal_from(index)
+    }
+}
+
+impl<S, F> AttributePolygonGenerator<Normal<S>> for ParametricSurface<S, F>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+    F: Fn(Scalar<S>, Scalar<S>) -> S,
+{
+    type Output = Tetragon<Unit<Vector<S>>>;
+
+    fn polygon_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Normal<S>>::indexing_polygon(self, index)
+            .map(|index| self.vertex_with_normal_from(index))
+    }
+}
+
+impl<S, F, T> IndexingPolygonGenerator<Normal<T>> for ParametricSurface<S, F>
+where
+    S: EuclideanSpace,
+{
+    type Output = Tetragon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Position<T>>::indexing_polygon(self, index)
+    }
+}
+
+impl<S, F> AttributeGenerator<Position<S>> for ParametricSurface<S, F>
+where
+    S: EuclideanSpace,
+{
+    type State = ();
+}
+
+impl<S, F> AttributeVertexGenerator<Position<S>> for ParametricSurface<S, F>
+where
+    S: EuclideanSpace,
+    F: Fn(Scalar<S>, Scalar<S>) -> S,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        self.column_count() * self.row_count()
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        self.vertex_with_position_from(index)
+    }
+}
+
+impl<S, F> AttributePolygonGenerator<Position<S>> for ParametricSurface<S, F>
+where
+    S: EuclideanSpace,
+    F: Fn(Scalar<S>, Scalar<S>) -> S,
+{
+    type Output = Tetragon<S>;
+
+    fn polygon_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Position<S>>::indexing_polygon(self, index)
+            .map(|index| self.vertex_with_position_from(index))
+    }
+}
+
+impl<S, F, T> IndexingPolygonGenerator<Position<T>> for ParametricSurface<S, F>
+where
+    S: EuclideanSpace,
+{
+    type Output = Tetragon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        assert!(index < self.polygon_count());
+        let (u, v) = (index % self.nu, index / self.nu);
+        let (p, q) = (u + 1, v + 1);
+        Tetragon::new(
+            self.index_for_position(u, v),
+            self.index_for_position(p, v),
+            self.index_for_position(p, q),
+            self.index_for_position(u, q),
+        )
+    }
+}
+
+impl<S, F> Generator for ParametricSurface<S, F> where S: EuclideanSpace {}
+
+// Gets the indices of the samples on either side of the sample at `i` in a
+// parameter with `n` segments.
+fn neighbors(i: usize, n: usize, is_wrapped: bool) -> (usize, usize) {
+    if is_wrapped {
+        ((i + n - 1) % n, (i + 1) % n)
+    }
+    else {
+        (i.saturating_sub(1), cmp::min(i + 1, n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+    use std::f64::consts::PI;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::generate::{Normal, Position};
+    use crate::primitive::parametric::ParametricSurface;
+
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn plane_vertex_and_polygon_count() {
+        let plane = ParametricSurface::new(4, 3, |u: f64, v: f64| E3::new(u, v, 0.0));
+        assert_eq!(20, plane.vertices::<Position<E3>>().count());
+        assert_eq!(12, plane.polygons::<Position<E3>>().count());
+        assert!(plane
+            .vertices::<Normal<E3>>()
+            .all(|normal| (normal.into_inner().z - 1.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn torus_volume() {
+        let (major, minor) = (1.0, 0.25);
+        let torus = ParametricSurface::new(64, 32, |u: f64, v: f64| {
+            let r = major + (minor * v.cos());
+            E3::new(r * u.cos(), r * u.sin(), minor * v.sin())
+        })
+        .with_domain(0.0..(2.0 * PI), 0.0..(2.0 * PI))
+        .with_wrap(true, true);
+        assert_eq!(64 * 32, torus.vertices::<Position<E3>>().count());
+
+        let graph = MeshGraph::<E3>::from_raw_buffers(
+            torus.indexing_polygons::<Position>(),
+            torus.vertices::<Position<E3>>(),
+        )
+        .unwrap();
+        // The volume of a torus is $2 \pi^2 R r^2$. The polyhedral
+        // approximation is slightly smaller.
+        let volume = 2.0 * PI * PI * major * minor * minor;
+        let error = (volume - graph.volume().unwrap()) / volume;
+        assert!(error > 0.0 && error < 0.02);
+    }
+
+    #[test]
+    fn sphere_pole_normals() {
+        // Parameterize by polar angle in $u$ and azimuth in $v$ so that the
+        // surface faces outward.
+        let sphere = ParametricSurface::new(8, 16, |u: f64, v: f64| {
+            E3::new(u.sin() * v.cos(), u.sin() * v.sin(), u.cos())
+        })
+        .with_domain(0.0..PI, 0.0..(2.0 * PI))
+        .with_wrap(false, true);
+        let positions = sphere.vertices::<Position<E3>>().collect::<Vec<_>>();
+        for (position, normal) in positions.iter().zip(sphere.vertices::<Normal<E3>>()) {
+            // Samples converge at the poles, where normals are approximated
+            // from the adjacent parallel.
+            assert!(position.coords.dot(&normal.into_inner()) > 0.9);
+        }
+    }
+}
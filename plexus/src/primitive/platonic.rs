@@ -0,0 +1,498 @@
+//! Platonic solid primitives.
+//!
+//! This module provides generators for the tetrahedron, octahedron,
+//! dodecahedron, and icosahedron. Each solid is inscribed in a sphere
+//! described by its [`Bounds`] and is centered at the origin. Faces are
+//! flat, so [`Normal`] data is indexed per face rather than per vertex.
+//!
+//! The faces of a [`Dodecahedron`] are pentagons, which are emitted as-is and
+//! are not triangulated. Use [`MeshBufferN`] or [`MeshGraph`] to collect them.
+//! See also the [`Cube`] primitive, which is the remaining platonic solid.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::N64;
+//! use nalgebra::Point3;
+//! use plexus::graph::MeshGraph;
+//! use plexus::prelude::*;
+//! use plexus::primitive::generate::Position;
+//! use plexus::primitive::platonic::Dodecahedron;
+//!
+//! type E3 = Point3<N64>;
+//!
+//! let dodecahedron = Dodecahedron::new();
+//! let graph = MeshGraph::<E3>::from_raw_buffers(
+//!     dodecahedron.indexing_polygons::<Position>(),
+//!     dodecahedron.vertices::<Position<E3>>(),
+//! )
+//! .unwrap();
+//! ```
+//!
+//! [`Cube`]: crate::primitive::cube::Cube
+//! [`MeshBufferN`]: crate::buffer::MeshBufferN
+//! [`MeshGraph`]: crate::graph::MeshGraph
+//! [`Normal`]: crate::primitive::generate::Normal
+
+use num::One;
+use theon::adjunct::{Converged, Map};
+use theon::query::Unit;
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
+use typenum::U3;
+
+use crate::primitive::generate::{
+    AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
+    IndexingPolygonGenerator, Normal, PolygonGenerator, Position,
+};
+use crate::primitive::{into_scalar, NGon};
+
+const PHI: f64 = 1.618_033_988_749_895;
+const INVERSE_PHI: f64 = PHI - 1.0;
+
+const TETRAHEDRON_POSITIONS: [[f64; 3]; 4] = [
+    [1.0, 1.0, 1.0],
+    [1.0, -1.0, -1.0],
+    [-1.0, 1.0, -1.0],
+    [-1.0, -1.0, 1.0],
+];
+const TETRAHEDRON_POLYGONS: [[usize; 3]; 4] = [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]];
+
+const OCTAHEDRON_POSITIONS: [[f64; 3]; 6] = [
+    [1.0, 0.0, 0.0],
+    [-1.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0],
+    [0.0, -1.0, 0.0],
+    [0.0, 0.0, 1.0],
+    [0.0, 0.0, -1.0],
+];
+const OCTAHEDRON_POLYGONS: [[usize; 3]; 8] = [
+    [0, 2, 4],
+    [1, 4, 2],
+    [0, 4, 3],
+    [0, 5, 2],
+    [1, 3, 4],
+    [1, 2, 5],
+    [0, 3, 5],
+    [1, 5, 3],
+];
+
+const DODECAHEDRON_POSITIONS: [[f64; 3]; 20] = [
+    [-1.0, -1.0, -1.0],
+    [-1.0, -1.0, 1.0],
+    [-1.0, 1.0, -1.0],
+    [-1.0, 1.0, 1.0],
+    [1.0, -1.0, -1.0],
+    [1.0, -1.0, 1.0],
+    [1.0, 1.0, -1.0],
+    [1.0, 1.0, 1.0],
+    [0.0, -INVERSE_PHI, -PHI],
+    [0.0, -INVERSE_PHI, PHI],
+    [0.0, INVERSE_PHI, -PHI],
+    [0.0, INVERSE_PHI, PHI],
+    [-INVERSE_PHI, -PHI, 0.0],
+    [-INVERSE_PHI, PHI, 0.0],
+    [INVERSE_PHI, -PHI, 0.0],
+    [INVERSE_PHI, PHI, 0.0],
+    [-PHI, 0.0, -INVERSE_PHI],
+    [-PHI, 0.0, INVERSE_PHI],
+    [PHI, 0.0, -INVERSE_PHI],
+    [PHI, 0.0, INVERSE_PHI],
+];
+const DODECAHEDRON_POLYGONS: [[usize; 5]; 12] = [
+    [0, 8, 4, 14, 12],
+    [1, 12, 14, 5, 9],
+    [2, 13, 15, 6, 10],
+    [3, 11, 7, 15, 13],
+    [0, 12, 1, 17, 16],
+    [2, 16, 17, 3, 13],
+    [4, 18, 19, 5, 14],
+    [6, 15, 7, 19, 18],
+    [0, 16, 2, 10, 8],
+    [1, 9, 11, 3, 17],
+    [4, 8, 10, 6, 18],
+    [5, 19, 7, 11, 9],
+];
+
+pub(in crate::primitive) const ICOSAHEDRON_POSITIONS: [[f64; 3]; 12] = [
+    [-1.0, PHI, 0.0],
+    [1.0, PHI, 0.0],
+    [-1.0, -PHI, 0.0],
+    [1.0, -PHI, 0.0],
+    [0.0, -1.0, PHI],
+    [0.0, 1.0, PHI],
+    [0.0, -1.0, -PHI],
+    [0.0, 1.0, -PHI],
+    [PHI, 0.0, -1.0],
+    [PHI, 0.0, 1.0],
+    [-PHI, 0.0, -1.0],
+    [-PHI, 0.0, 1.0],
+];
+pub(in crate::primitive) const ICOSAHEDRON_POLYGONS: [[usize; 3]; 20] = [
+    [0, 11, 5],
+    [0, 5, 1],
+    [0, 1, 7],
+    [0, 7, 10],
+    [0, 10, 11],
+    [1, 5, 9],
+    [5, 11, 4],
+    [11, 10, 2],
+    [10, 7, 6],
+    [7, 1, 8],
+    [3, 9, 4],
+    [3, 4, 2],
+    [3, 2, 6],
+    [3, 6, 8],
+    [3, 8, 9],
+    [4, 9, 5],
+    [2, 4, 11],
+    [6, 2, 10],
+    [8, 6, 7],
+    [9, 8, 1],
+];
+
+/// Bounds of a platonic solid.
+///
+/// The radius of a platonic solid is the radius of its circumscribed sphere,
+/// so all vertices lie at this distance from the origin.
+#[derive(Clone, Copy)]
+pub struct Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    radius: Scalar<S>,
+}
+
+impl<S> Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    pub fn with_radius(radius: Scalar<S>) -> Self {
+        Bounds { radius }
+    }
+
+    pub fn with_width(width: Scalar<S>) -> Self {
+        Self::with_radius(width / (Scalar::<S>::one() + One::one()))
+    }
+
+    pub fn unit_radius() -> Self {
+        Self::with_radius(One::one())
+    }
+
+    pub fn unit_width() -> Self {
+        Self::with_width(One::one())
+    }
+}
+
+impl<S> Default for Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    fn default() -> Self {
+        Self::unit_radius()
+    }
+}
+
+macro_rules! impl_platonic {
+    (
+        $(#[$attr:meta])*
+        solid => $t:ident,
+        arity => $n:expr,
+        positions => $positions:ident,
+        polygons => $polygons:ident $(,)?
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy)]
+        pub struct $t;
+
+        impl $t {
+            pub fn new() -> Self {
+                $t
+            }
+
+            fn vertex_with_position_from<S>(&self, state: &Bounds<S>, index: usize) -> S
+            where
+                S: EuclideanSpace + FiniteDimensional<N = U3>,
+            {
+                let [x, y, z] = normalize($positions[index]);
+                S::from_xyz(
+                    state.radius * into_scalar::<_, S>(x),
+                    state.radius * into_scalar::<_, S>(y),
+                    state.radius * into_scalar::<_, S>(z),
+                )
+            }
+        }
+
+        impl Default for $t {
+            fn default() -> Self {
+                $t::new()
+            }
+        }
+
+        impl PolygonGenerator for $t {
+            fn polygon_count(&self) -> usize {
+                $polygons.len()
+            }
+        }
+
+        impl<S> AttributeGenerator<Normal<S>> for $t
+        where
+            S: EuclideanSpace + FiniteDimensional<N = U3>,
+        {
+            type State = ();
+        }
+
+        impl<S> AttributeVertexGenerator<Normal<S>> for $t
+        where
+            S: EuclideanSpace + FiniteDimensional<N = U3>,
+        {
+            type Output = Unit<Vector<S>>;
+
+            fn vertex_count(&self) -> usize {
+                self.polygon_count()
+            }
+
+            fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+                // The solid is centered at the origin, so the centroid of a
+                // face is parallel to its normal.
+                let [x, y, z] = normalize($polygons[index].iter().fold(
+                    [0.0; 3],
+                    |[x, y, z], &index| {
+                        let [p, q, r] = $positions[index];
+                        [x + p, y + q, z + r]
+                    },
+                ));
+                let normal = S::from_xyz(
+                    into_scalar::<_, S>(x),
+                    into_scalar::<_, S>(y),
+                    into_scalar::<_, S>(z),
+                );
+                Unit::try_from_inner(normal.into_coordinates()).expect("non-zero vector")
+            }
+        }
+
+        impl<S> AttributePolygonGenerator<Normal<S>> for $t
+        where
+            S: EuclideanSpace + FiniteDimensional<N = U3>,
+        {
+            type Output = NGon<Unit<Vector<S>>, { $n }>;
+
+            fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+                IndexingPolygonGenerator::<Normal<S>>::indexing_polygon(self, index).map(|index| {
+                    AttributeVertexGenerator::<Normal<S>>::vertex_from(self, state, index)
+                })
+            }
+        }
+
+        impl<S> IndexingPolygonGenerator<Normal<S>> for $t {
+            type Output = NGon<usize, { $n }>;
+
+            fn indexing_polygon(&self, index: usize) -> Self::Output {
+                assert!(index < self.polygon_count());
+                NGon::converged(index)
+            }
+        }
+
+        impl<S> AttributeGenerator<Position<S>> for $t
+        where
+            S: EuclideanSpace + FiniteDimensional<N = U3>,
+        {
+            type State = Bounds<S>;
+        }
+
+        impl<S> AttributeVertexGenerator<Position<S>> for $t
+        where
+            S: EuclideanSpace + FiniteDimensional<N = U3>,
+        {
+            type Output = S;
+
+            fn vertex_count(&self) -> usize {
+                $positions.len()
+            }
+
+            fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+                self.vertex_with_position_from(state, index)
+            }
+        }
+
+        impl<S> AttributePolygonGenerator<Position<S>> for $t
+        where
+            S: EuclideanSpace + FiniteDimensional<N = U3>,
+        {
+            type Output = NGon<S, { $n }>;
+
+            fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+                IndexingPolygonGenerator::<Position<S>>::indexing_polygon(self, index)
+                    .map(|index| self.vertex_with_position_from(state, index))
+            }
+        }
+
+        impl<S> IndexingPolygonGenerator<Position<S>> for $t {
+            type Output = NGon<usize, { $n }>;
+
+            fn indexing_polygon(&self, index: usize) -> Self::Output {
+                NGon($polygons[index])
+            }
+        }
+
+        impl Generator for $t {}
+    };
+}
+
+impl_platonic!(
+    /// Regular tetrahedron with four triangular faces.
+    solid => Tetrahedron,
+    arity => 3,
+    positions => TETRAHEDRON_POSITIONS,
+    polygons => TETRAHEDRON_POLYGONS,
+);
+impl_platonic!(
+    /// Regular octahedron with eight triangular faces.
+    ///
+    /// The vertices of the octahedron lie on the basis axes.
+    solid => Octahedron,
+    arity => 3,
+    positions => OCTAHEDRON_POSITIONS,
+    polygons => OCTAHEDRON_POLYGONS,
+);
+impl_platonic!(
+    /// Regular dodecahedron with twelve pentagonal faces.
+    solid => Dodecahedron,
+    arity => 5,
+    positions => DODECAHEDRON_POSITIONS,
+    polygons => DODECAHEDRON_POLYGONS,
+);
+impl_platonic!(
+    /// Regular icosahedron with twenty triangular faces.
+    ///
+    /// This is equivalent to an [`IcoSphere`] without subdivision, but uses
+    /// flat face normals.
+    ///
+    /// [`IcoSphere`]: crate::primitive::sphere::IcoSphere
+    solid => Icosahedron,
+    arity => 3,
+    positions => ICOSAHEDRON_POSITIONS,
+    polygons => ICOSAHEDRON_POLYGONS,
+);
+
+pub(in crate::primitive) fn normalize([x, y, z]: [f64; 3]) -> [f64; 3] {
+    let magnitude = ((x * x) + (y * y) + (z * z)).sqrt();
+    [x / magnitude, y / magnitude, z / magnitude]
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+    use std::convert::TryFrom;
+
+    use crate::buffer::MeshBufferN;
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::generate::{Normal, Position};
+    use crate::primitive::platonic::{Bounds, Dodecahedron, Icosahedron, Octahedron, Tetrahedron};
+    use crate::primitive::UnboundedPolygon;
+
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn vertex_and_polygon_count() {
+        assert_eq!(4, Tetrahedron::new().vertices::<Position<E3>>().count());
+        assert_eq!(4, Tetrahedron::new().polygons::<Position<E3>>().count());
+        assert_eq!(6, Octahedron::new().vertices::<Position<E3>>().count());
+        assert_eq!(8, Octahedron::new().polygons::<Position<E3>>().count());
+        assert_eq!(20, Dodecahedron::new().vertices::<Position<E3>>().count());
+        assert_eq!(12, Dodecahedron::new().polygons::<Position<E3>>().count());
+        assert_eq!(12, Icosahedron::new().vertices::<Position<E3>>().count());
+        assert_eq!(20, Icosahedron::new().polygons::<Position<E3>>().count());
+    }
+
+    #[test]
+    fn octahedron_volume() {
+        let octahedron = Octahedron::new();
+        let graph = MeshGraph::<E3>::from_raw_buffers(
+            octahedron.indexing_polygons::<Position>(),
+            octahedron.vertices_from::<Position<E3>>(Bounds::with_radius(2.0)),
+        )
+        .unwrap();
+        // The volume of an octahedron with circumradius $r$ is $\frac{4}{3}r^3$.
+        assert!((graph.volume().unwrap() - (32.0 / 3.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn solids_are_closed() {
+        let volume = |polygons: Vec<UnboundedPolygon<usize>>, positions: Vec<E3>| {
+            MeshGraph::<E3>::from_raw_buffers(polygons, positions)
+                .unwrap()
+                .volume()
+                .unwrap()
+        };
+
+        // Faces are wound outward, so the volume is positive.
+        let tetrahedron = Tetrahedron::new();
+        assert!(
+            volume(
+                tetrahedron
+                    .indexing_polygons::<Position>()
+                    .map(UnboundedPolygon::from)
+                    .collect(),
+                tetrahedron.vertices::<Position<E3>>().collect(),
+            ) > 0.0
+        );
+        let octahedron = Octahedron::new();
+        assert!(
+            volume(
+                octahedron
+                    .indexing_polygons::<Position>()
+                    .map(UnboundedPolygon::from)
+                    .collect(),
+                octahedron.vertices::<Position<E3>>().collect(),
+            ) > 0.0
+        );
+        let dodecahedron = Dodecahedron::new();
+        assert!(
+            volume(
+                dodecahedron
+                    .indexing_polygons::<Position>()
+                    .map(UnboundedPolygon::from)
+                    .collect(),
+                dodecahedron.vertices::<Position<E3>>().collect(),
+            ) > 0.0
+        );
+        let icosahedron = Icosahedron::new();
+        assert!(
+            volume(
+                icosahedron
+                    .indexing_polygons::<Position>()
+                    .map(UnboundedPolygon::from)
+                    .collect(),
+                icosahedron.vertices::<Position<E3>>().collect(),
+            ) > 0.0
+        );
+    }
+
+    #[test]
+    fn dodecahedron_pentagons() {
+        let dodecahedron = Dodecahedron::new();
+        let buffer = MeshBufferN::<usize, E3>::from_raw_buffers(
+            dodecahedron.indexing_polygons::<Position>(),
+            dodecahedron.vertices::<Position<E3>>(),
+        )
+        .unwrap();
+        assert_eq!(12, buffer.as_index_slice().len());
+        assert_eq!(20, buffer.as_vertex_slice().len());
+        assert!(buffer
+            .as_index_slice()
+            .iter()
+            .all(|polygon| polygon.arity() == 5));
+
+        let graph = MeshGraph::<E3>::try_from(buffer).unwrap();
+        assert_eq!(12, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 5));
+        assert!(Dodecahedron::new()
+            .vertices::<Normal<E3>>()
+            .all(|normal| (normal.into_inner().norm() - 1.0).abs() < 1e-9));
+    }
+}
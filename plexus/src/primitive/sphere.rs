@@ -26,18 +26,20 @@
 //! ```
 
 use decorum::Real;
-use num::{NumCast, One, ToPrimitive};
+use num::One;
 use std::cmp;
+use std::collections::HashMap;
 use theon::adjunct::Map;
 use theon::query::Unit;
 use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
-use typenum::U3;
+use typenum::{U2, U3};
 
 use crate::primitive::generate::{
     AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
-    IndexingPolygonGenerator, Normal, PolygonGenerator, Position,
+    IndexingPolygonGenerator, Normal, PolygonGenerator, Position, UvMap,
 };
-use crate::primitive::{BoundedPolygon, Tetragon, Trigon};
+use crate::primitive::platonic::{normalize, ICOSAHEDRON_POLYGONS, ICOSAHEDRON_POSITIONS};
+use crate::primitive::{into_scalar, BoundedPolygon, Tetragon, Trigon};
 
 #[derive(Clone, Copy)]
 pub struct Bounds<S>
@@ -124,6 +126,23 @@ impl UvSphere {
         }
     }
 
+    fn vertex_with_uv_from<S>(&self, u: usize, v: usize) -> S
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U2>,
+    {
+        S::from_xy(
+            into_scalar::<_, S>(u) / into_scalar::<_, S>(self.nu),
+            Scalar::<S>::one() - (into_scalar::<_, S>(v) / into_scalar::<_, S>(self.nv)),
+        )
+    }
+
+    // Unlike positions, texture coordinates do not wrap: there is a seam of
+    // `nv + 1` coordinates at `u = nu` and each polygon at a pole has its own
+    // coordinate for the pole.
+    fn index_for_uv(&self, u: usize, v: usize) -> usize {
+        (v * (self.nu + 1)) + u
+    }
+
     fn map_polygon_index(&self, index: usize) -> (usize, usize) {
         (index % self.nu, index / self.nu)
     }
@@ -304,24 +323,258 @@ impl<S> IndexingPolygonGenerator<Position<S>> for UvSphere {
     }
 }
 
+impl<S> AttributeGenerator<UvMap<S>> for UvSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<UvMap<S>> for UvSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        (self.nu + 1) * (self.nv + 1)
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        self.vertex_with_uv_from(index % (self.nu + 1), index / (self.nu + 1))
+    }
+}
+
+impl<S> AttributePolygonGenerator<UvMap<S>> for UvSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type Output = BoundedPolygon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<UvMap<S>>::indexing_polygon(self, index)
+            .map(|index| AttributeVertexGenerator::<UvMap<S>>::vertex_from(self, state, index))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<UvMap<S>> for UvSphere {
+    type Output = BoundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        let (u, v) = self.map_polygon_index(index);
+        let (p, q) = (u + 1, v + 1);
+
+        // The winding and vertex order match the positional indexing polygons.
+        let low = self.index_for_uv(u, v);
+        let high = self.index_for_uv(p, q);
+        if v == 0 {
+            Trigon::new(low, self.index_for_uv(u, q), high).into()
+        }
+        else if v == self.nv - 1 {
+            Trigon::new(self.index_for_uv(u, q), self.index_for_uv(p, v), low).into()
+        }
+        else {
+            Tetragon::new(low, self.index_for_uv(u, q), high, self.index_for_uv(p, v)).into()
+        }
+    }
+}
+
 impl Generator for UvSphere {}
 
-fn into_scalar<T, S>(value: T) -> Scalar<S>
+/// Geodesic sphere formed by subdividing an icosahedron.
+///
+/// Unlike [`UvSphere`], the triangles of an icosphere are nearly uniform in
+/// size and shape and there are no poles. Each level of subdivision splits
+/// every triangle into four triangles and projects the new vertices onto the
+/// sphere, so an icosphere has $20 \times 4^n$ triangles for a subdivision
+/// level $n$.
+#[derive(Clone)]
+pub struct IcoSphere {
+    // Positions on the unit sphere.
+    positions: Vec<[f64; 3]>,
+    polygons: Vec<Trigon<usize>>,
+}
+
+impl IcoSphere {
+    /// The greatest subdivision level of an icosphere.
+    ///
+    /// An icosphere at this level has $20 \times 4^8 = 1310720$ triangles.
+    pub const MAX_LEVEL: usize = 8;
+
+    /// Creates an icosphere with the given subdivision level.
+    ///
+    /// Subdivision is performed eagerly and the number of triangles grows
+    /// exponentially with the level, so the level is clamped to
+    /// [`IcoSphere::MAX_LEVEL`].
+    pub fn new(level: usize) -> Self {
+        let mut positions = ICOSAHEDRON_POSITIONS
+            .iter()
+            .copied()
+            .map(normalize)
+            .collect::<Vec<_>>();
+        let mut polygons = ICOSAHEDRON_POLYGONS
+            .iter()
+            .map(|&[a, b, c]| Trigon::new(a, b, c))
+            .collect::<Vec<_>>();
+        for _ in 0..cmp::min(level, IcoSphere::MAX_LEVEL) {
+            // Midpoints are shared by the triangles on either side of an edge.
+            let mut midpoints = HashMap::new();
+            let mut midpoint = |a: usize, b: usize| {
+                *midpoints
+                    .entry((cmp::min(a, b), cmp::max(a, b)))
+                    .or_insert_with(|| {
+                        let (p, q) = (positions[a], positions[b]);
+                        positions.push(normalize([p[0] + q[0], p[1] + q[1], p[2] + q[2]]));
+                        positions.len() - 1
+                    })
+            };
+            polygons = polygons
+                .into_iter()
+                .flat_map(|polygon| {
+                    let [a, b, c] = polygon.into_array();
+                    let (ab, bc, ca) = (midpoint(a, b), midpoint(b, c), midpoint(c, a));
+                    [
+                        Trigon::new(a, ab, ca),
+                        Trigon::new(b, bc, ab),
+                        Trigon::new(c, ca, bc),
+                        Trigon::new(ab, bc, ca),
+                    ]
+                })
+                .collect();
+        }
+        IcoSphere {
+            positions,
+            polygons,
+        }
+    }
+
+    fn vertex_with_position_from<S>(
+        &self,
+        state: &<Self as AttributeGenerator<Position<S>>>::State,
+        index: usize,
+    ) -> S
+    where
+        Self: AttributeGenerator<Position<S>, State = Bounds<S>>,
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let [x, y, z] = self.positions[index];
+        S::from_xyz(
+            state.radius * into_scalar::<_, S>(x),
+            state.radius * into_scalar::<_, S>(y),
+            state.radius * into_scalar::<_, S>(z),
+        )
+    }
+}
+
+impl Default for IcoSphere {
+    fn default() -> Self {
+        IcoSphere::new(2)
+    }
+}
+
+impl PolygonGenerator for IcoSphere {
+    fn polygon_count(&self) -> usize {
+        self.polygons.len()
+    }
+}
+
+impl<S> AttributeGenerator<Normal<S>> for IcoSphere
 where
-    T: ToPrimitive,
-    S: EuclideanSpace,
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<Normal<S>> for IcoSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
 {
-    <Scalar<S> as NumCast>::from(value).unwrap()
+    type Output = Unit<Vector<S>>;
+
+    fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        let position =
+            AttributeVertexGenerator::<Position<S>>::vertex_from(self, &Default::default(), index);
+        Unit::try_from_inner(position.into_coordinates()).expect("non-zero vector")
+    }
+}
+
+impl<S> AttributePolygonGenerator<Normal<S>> for IcoSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Trigon<Unit<Vector<S>>>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        self.polygons[index].map(|index| {
+            AttributeVertexGenerator::<Normal<S>>::vertex_from(self, state, index)
+        })
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Normal<S>> for IcoSphere {
+    type Output = Trigon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        self.polygons[index]
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for IcoSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
 }
 
+impl<S> AttributeVertexGenerator<Position<S>> for IcoSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        self.vertex_with_position_from(state, index)
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for IcoSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Trigon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        self.polygons[index].map(|index| self.vertex_with_position_from(state, index))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Position<S>> for IcoSphere {
+    type Output = Trigon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        self.polygons[index]
+    }
+}
+
+impl Generator for IcoSphere {}
+
 #[cfg(test)]
 mod tests {
-    use nalgebra::Point3;
+    use nalgebra::{Point2, Point3};
     use std::collections::BTreeSet;
 
+    use crate::graph::MeshGraph;
     use crate::prelude::*;
-    use crate::primitive::generate::Position;
-    use crate::primitive::sphere::UvSphere;
+    use crate::primitive::generate::{Normal, Position, UvMap};
+    use crate::primitive::sphere::{Bounds, IcoSphere, UvSphere};
 
     type E3 = Point3<f64>;
 
@@ -357,4 +610,49 @@ mod tests {
                 .len()
         )
     }
+
+    #[test]
+    fn uv_map_seam() {
+        let sphere = UvSphere::new(4, 3);
+        let uvs = sphere.vertices::<UvMap<Point2<f64>>>().collect::<Vec<_>>();
+        assert_eq!(20, uvs.len());
+        assert!(uvs
+            .iter()
+            .all(|uv| (0.0..=1.0).contains(&uv.x) && (0.0..=1.0).contains(&uv.y)));
+        // The seam column duplicates the first column at `u = 1`.
+        assert!(uvs.iter().any(|uv| uv.x == 1.0));
+        assert!(sphere
+            .indexing_polygons::<UvMap>()
+            .zip(sphere.indexing_polygons::<Position>())
+            .all(|(uv, position)| uv.arity() == position.arity()));
+    }
+
+    #[test]
+    fn icosphere_vertex_and_polygon_count() {
+        let sphere = IcoSphere::new(0);
+        assert_eq!(12, sphere.vertices::<Position<E3>>().count());
+        assert_eq!(20, sphere.polygons::<Position<E3>>().count());
+
+        let sphere = IcoSphere::new(2);
+        assert_eq!(162, sphere.vertices::<Position<E3>>().count());
+        assert_eq!(320, sphere.polygons::<Position<E3>>().count());
+    }
+
+    #[test]
+    fn icosphere_is_closed() {
+        let sphere = IcoSphere::new(1);
+        let graph = MeshGraph::<E3>::from_raw_buffers(
+            sphere.indexing_polygons::<Position>(),
+            sphere.vertices_from::<Position<E3>>(Bounds::with_radius(2.0)),
+        )
+        .unwrap();
+        assert!(graph
+            .vertices()
+            .all(|vertex| (vertex.position().coords.norm() - 2.0).abs() < 1e-9));
+        // Faces are wound outward, so the volume is positive.
+        assert!(graph.volume().unwrap() > 0.0);
+        assert!(sphere
+            .vertices::<Normal<E3>>()
+            .all(|normal| (normal.into_inner().norm() - 1.0).abs() < 1e-9));
+    }
 }
@@ -0,0 +1,550 @@
+//! Swept and revolved primitives.
+//!
+//! This module provides generators that sweep a closed profile along a path
+//! ([`Sweep`]) and revolve a profile about an axis ([`Lathe`]). Profiles are
+//! given as two-dimensional coordinates.
+//!
+//! # Examples
+//!
+//! Generating a graph from a pipe that bends around a corner:
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use nalgebra::Point3;
+//! use plexus::graph::MeshGraph;
+//! use plexus::prelude::*;
+//! use plexus::primitive::generate::Position;
+//! use plexus::primitive::sweep::Sweep;
+//!
+//! type E3 = Point3<f64>;
+//!
+//! let profile = (0..8).map(|n| {
+//!     let theta = (n as f64) * std::f64::consts::PI / 4.0;
+//!     (0.25 * theta.cos(), 0.25 * theta.sin())
+//! });
+//! let sweep = Sweep::new(
+//!     profile,
+//!     vec![
+//!         E3::new(0.0, 0.0, 0.0),
+//!         E3::new(0.0, 0.0, 2.0),
+//!         E3::new(0.0, 2.0, 2.0),
+//!     ],
+//! );
+//! let graph = MeshGraph::<E3>::from_raw_buffers(
+//!     sweep.indexing_polygons::<Position>(),
+//!     sweep.vertices::<Position<E3>>(),
+//! )
+//! .unwrap();
+//! ```
+
+use decorum::Real;
+use num::{One, Zero};
+use std::cmp;
+use theon::ops::{Cross, Dot};
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use typenum::U3;
+
+use crate::primitive::generate::{
+    AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
+    IndexingPolygonGenerator, PolygonGenerator, Position,
+};
+use crate::primitive::{into_scalar, BoundedPolygon, Tetragon, Trigon};
+
+/// Orientation of the profile of a [`Sweep`] along its path.
+#[derive(Clone, Copy)]
+pub enum Frame<S>
+where
+    S: EuclideanSpace,
+{
+    /// Rotation minimizing frames.
+    ///
+    /// The profile twists as little as possible along the path. Frames are
+    /// computed via the double reflection method.
+    RotationMinimizing,
+    /// Frames that align the $x$ axis of the profile with the projection of an
+    /// up vector into the plane of the profile.
+    ///
+    /// Where the path is parallel to the up vector, the orientation of the
+    /// preceding frame is used.
+    Fixed(Vector<S>),
+}
+
+impl<S> Default for Frame<S>
+where
+    S: EuclideanSpace,
+{
+    fn default() -> Self {
+        Frame::RotationMinimizing
+    }
+}
+
+/// Closed profile swept along a path.
+///
+/// The profile is placed in the plane orthogonal to the path at each of its
+/// points, where the tangent of the path bisects adjacent segments. Profiles
+/// should be wound counterclockwise, in which case the faces of the sweep face
+/// outward. The profile is not scaled at corners, so sharp turns in the path
+/// narrow the sweep.
+///
+/// Caps are fanned about the centroid of the profile and so require profiles
+/// that are star-shaped about their centroid.
+#[derive(Clone)]
+pub struct Sweep<S>
+where
+    S: EuclideanSpace,
+{
+    profile: Vec<(Scalar<S>, Scalar<S>)>,
+    path: Vec<S>,
+    // Origin, profile $x$ axis, and profile $y$ axis at each point of the path.
+    frames: Vec<(S, Vector<S>, Vector<S>)>,
+    is_capped: bool,
+}
+
+impl<S> Sweep<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    /// Creates a capped sweep with rotation minimizing frames.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the profile has fewer than three points, the path has fewer
+    /// than two points, or adjacent points in the path are coincident.
+    pub fn new<P, Q>(profile: P, path: Q) -> Self
+    where
+        P: IntoIterator<Item = (Scalar<S>, Scalar<S>)>,
+        Q: IntoIterator<Item = S>,
+    {
+        let profile = profile.into_iter().collect::<Vec<_>>();
+        let path = path.into_iter().collect::<Vec<_>>();
+        assert!(profile.len() >= 3, "profile with fewer than three points");
+        assert!(path.len() >= 2, "path with fewer than two points");
+        let mut sweep = Sweep {
+            profile,
+            path,
+            frames: Vec::new(),
+            is_capped: true,
+        };
+        sweep.frames = sweep.frames_from(Frame::RotationMinimizing);
+        sweep
+    }
+
+    pub fn with_frame(mut self, frame: Frame<S>) -> Self {
+        self.frames = self.frames_from(frame);
+        self
+    }
+
+    pub fn with_caps(mut self, is_capped: bool) -> Self {
+        self.is_capped = is_capped;
+        self
+    }
+
+    fn frames_from(&self, frame: Frame<S>) -> Vec<(S, Vector<S>, Vector<S>)> {
+        let directions = self
+            .path
+            .iter()
+            .zip(self.path[1..].iter())
+            .map(|(a, b)| (*b - *a).normalize().expect("coincident points in path"))
+            .collect::<Vec<_>>();
+        let tangents = (0..self.path.len())
+            .map(|n| {
+                let previous = directions[n.saturating_sub(1)];
+                let next = directions[cmp::min(n, directions.len() - 1)];
+                // Reversals of the path have no bisecting plane.
+                (previous + next).normalize().unwrap_or(next)
+            })
+            .collect::<Vec<_>>();
+        let mut normal = orthogonal::<S>(tangents[0]);
+        let mut frames = Vec::with_capacity(self.path.len());
+        for (n, (point, tangent)) in self.path.iter().zip(tangents.iter()).enumerate() {
+            normal = match frame {
+                Frame::RotationMinimizing => {
+                    if n == 0 {
+                        normal
+                    }
+                    else {
+                        let segment = *point - self.path[n - 1];
+                        let normal = reflect::<S>(normal, segment);
+                        let reflection = *tangent - reflect::<S>(tangents[n - 1], segment);
+                        reflect::<S>(normal, reflection)
+                    }
+                }
+                Frame::Fixed(up) => (up - (*tangent * tangent.dot(up)))
+                    .normalize()
+                    .unwrap_or(normal),
+            };
+            frames.push((*point, normal, tangent.cross(normal)));
+        }
+        frames
+    }
+
+    fn vertex_with_position_from(&self, index: usize) -> S {
+        let m = self.profile.len();
+        let count = self.path.len() * m;
+        let (i, (x, y)) = if index < count {
+            (index / m, self.profile[index % m])
+        }
+        else {
+            let n = into_scalar::<_, S>(m);
+            let (x, y) = self
+                .profile
+                .iter()
+                .fold((Zero::zero(), Zero::zero()), |(sx, sy): (Scalar<S>, Scalar<S>), (x, y)| {
+                    (sx + *x, sy + *y)
+                });
+            let i = if index == count { 0 } else { self.path.len() - 1 };
+            (i, (x / n, y / n))
+        };
+        let (origin, u, v) = self.frames[i];
+        origin + (u * x) + (v * y)
+    }
+}
+
+impl<S> PolygonGenerator for Sweep<S>
+where
+    S: EuclideanSpace,
+{
+    fn polygon_count(&self) -> usize {
+        let m = self.profile.len();
+        ((self.path.len() - 1) * m) + if self.is_capped { 2 * m } else { 0 }
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for Sweep<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for Sweep<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        (self.path.len() * self.profile.len()) + if self.is_capped { 2 } else { 0 }
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        self.vertex_with_position_from(index)
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for Sweep<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    type Output = BoundedPolygon<S>;
+
+    fn polygon_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Position<S>>::indexing_polygon(self, index)
+            .map(|index| self.vertex_with_position_from(index))
+    }
+}
+
+impl<S, T> IndexingPolygonGenerator<Position<T>> for Sweep<S>
+where
+    S: EuclideanSpace,
+{
+    type Output = BoundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        let m = self.profile.len();
+        let count = (self.path.len() - 1) * m;
+        let position = |i: usize, j: usize| (i * m) + (j % m);
+        if index < count {
+            let (i, j) = (index / m, index % m);
+            Tetragon::new(
+                position(i, j),
+                position(i, j + 1),
+                position(i + 1, j + 1),
+                position(i + 1, j),
+            )
+            .into()
+        }
+        else {
+            // Caps are fanned about the centroid of the profile, which follows
+            // the positions of the profile at each end of the path.
+            let j = (index - count) % m;
+            let center = self.path.len() * m;
+            if index - count < m {
+                Trigon::new(center, position(0, j + 1), position(0, j)).into()
+            }
+            else {
+                let i = self.path.len() - 1;
+                Trigon::new(center + 1, position(i, j), position(i, j + 1)).into()
+            }
+        }
+    }
+}
+
+impl<S> Generator for Sweep<S> where S: EuclideanSpace {}
+
+/// Profile revolved about the $z$ axis.
+///
+/// The profile is an open polyline of radius and height coordinates. Points
+/// with a radius of zero lie on the axis and are not duplicated, so profiles
+/// that begin and end on the axis produce closed surfaces. The faces of the
+/// lathe face outward when the profile ascends away from the axis (i.e., the
+/// surface faces to the right of the profile in the radius-height plane).
+#[derive(Clone)]
+pub struct Lathe<S>
+where
+    S: EuclideanSpace,
+{
+    profile: Vec<(Scalar<S>, Scalar<S>)>,
+    nu: usize, // Meridians.
+    // Index of the first position of each point in the profile.
+    offsets: Vec<usize>,
+    // Segments of the profile that do not lie on the axis.
+    segments: Vec<usize>,
+}
+
+impl<S> Lathe<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    /// Creates a lathe with `nu` meridians.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the profile has fewer than two points.
+    pub fn new<P>(profile: P, nu: usize) -> Self
+    where
+        P: IntoIterator<Item = (Scalar<S>, Scalar<S>)>,
+    {
+        let profile = profile.into_iter().collect::<Vec<_>>();
+        assert!(profile.len() >= 2, "profile with fewer than two points");
+        let nu = cmp::max(3, nu);
+        let mut offsets = Vec::with_capacity(profile.len() + 1);
+        let mut offset = 0;
+        for (radius, _) in profile.iter() {
+            offsets.push(offset);
+            offset += if radius.is_zero() { 1 } else { nu };
+        }
+        offsets.push(offset);
+        let segments = (0..(profile.len() - 1))
+            .filter(|k| !(profile[*k].0.is_zero() && profile[k + 1].0.is_zero()))
+            .collect();
+        Lathe {
+            profile,
+            nu,
+            offsets,
+            segments,
+        }
+    }
+
+    fn vertex_with_position_from(&self, k: usize, u: usize) -> S {
+        let (radius, height) = self.profile[k];
+        let one = Scalar::<S>::one();
+        let theta =
+            (into_scalar::<_, S>(u % self.nu) / into_scalar::<_, S>(self.nu)) * Real::PI * (one + one);
+        S::from_xyz(radius * theta.cos(), radius * theta.sin(), height)
+    }
+
+    fn is_on_axis(&self, k: usize) -> bool {
+        (self.offsets[k + 1] - self.offsets[k]) == 1
+    }
+}
+
+impl<S> PolygonGenerator for Lathe<S>
+where
+    S: EuclideanSpace,
+{
+    fn polygon_count(&self) -> usize {
+        self.segments.len() * self.nu
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for Lathe<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for Lathe<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        self.offsets[self.profile.len()]
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        let k = self.offsets.partition_point(|offset| *offset <= index) - 1;
+        self.vertex_with_position_from(k, index - self.offsets[k])
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for Lathe<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = BoundedPolygon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Position<S>>::indexing_polygon(self, index)
+            .map(|index| AttributeVertexGenerator::<Position<S>>::vertex_from(self, state, index))
+    }
+}
+
+impl<S, T> IndexingPolygonGenerator<Position<T>> for Lathe<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = BoundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        let (k, u) = (self.segments[index / self.nu], index % self.nu);
+        let position = |k: usize, u: usize| {
+            if self.is_on_axis(k) {
+                self.offsets[k]
+            }
+            else {
+                self.offsets[k] + (u % self.nu)
+            }
+        };
+        let (a, b) = (position(k, u), position(k, u + 1));
+        let (c, d) = (position(k + 1, u + 1), position(k + 1, u));
+        if self.is_on_axis(k) {
+            Trigon::new(a, c, d).into()
+        }
+        else if self.is_on_axis(k + 1) {
+            Trigon::new(a, b, c).into()
+        }
+        else {
+            Tetragon::new(a, b, c, d).into()
+        }
+    }
+}
+
+impl<S> Generator for Lathe<S> where S: EuclideanSpace {}
+
+// Gets an arbitrary unit vector orthogonal to the given unit vector.
+fn orthogonal<S>(vector: Vector<S>) -> Vector<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    let (x, y, z) = vector.into_xyz();
+    let (zero, one) = (Zero::zero(), One::one());
+    // Cross with the basis vector that is least aligned with the vector.
+    let axis = if x.abs() <= y.abs() && x.abs() <= z.abs() {
+        S::from_xyz(one, zero, zero)
+    }
+    else if y.abs() <= z.abs() {
+        S::from_xyz(zero, one, zero)
+    }
+    else {
+        S::from_xyz(zero, zero, one)
+    } - S::origin();
+    vector
+        .cross(axis)
+        .normalize()
+        .expect("non-zero vector")
+}
+
+// Reflects a vector through the plane orthogonal to another vector.
+fn reflect<S>(vector: Vector<S>, plane: Vector<S>) -> Vector<S>
+where
+    S: EuclideanSpace,
+{
+    let square = plane.dot(plane);
+    if square.is_zero() {
+        vector
+    }
+    else {
+        let two = Scalar::<S>::one() + One::one();
+        vector - (plane * ((two * plane.dot(vector)) / square))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+    use std::f64::consts::PI;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sweep::{Frame, Lathe, Sweep};
+
+    type E3 = Point3<f64>;
+
+    fn square() -> Vec<(f64, f64)> {
+        vec![(-0.5, -0.5), (0.5, -0.5), (0.5, 0.5), (-0.5, 0.5)]
+    }
+
+    #[test]
+    fn sweep_straight_path() {
+        let sweep = Sweep::new(
+            square(),
+            vec![
+                E3::new(0.0, 0.0, 0.0),
+                E3::new(0.0, 0.0, 1.0),
+                E3::new(0.0, 0.0, 2.0),
+            ],
+        );
+        assert_eq!(8 + 8, sweep.polygons::<Position<E3>>().count());
+        let graph = MeshGraph::<E3>::from_raw_buffers(
+            sweep.indexing_polygons::<Position>(),
+            sweep.vertices::<Position<E3>>(),
+        )
+        .unwrap();
+        assert!((graph.volume().unwrap() - 2.0).abs() < 1e-9);
+
+        let sweep = sweep.with_caps(false);
+        assert_eq!(8, sweep.polygons::<Position<E3>>().count());
+        assert_eq!(12, sweep.vertices::<Position<E3>>().count());
+    }
+
+    #[test]
+    fn sweep_frames() {
+        let path = vec![
+            E3::new(0.0, 0.0, 0.0),
+            E3::new(1.0, 0.0, 0.0),
+            E3::new(1.0, 1.0, 0.5),
+            E3::new(0.0, 1.0, 1.0),
+        ];
+        for frame in [Frame::RotationMinimizing, Frame::Fixed(nalgebra::Vector3::z())] {
+            let sweep = Sweep::new(square(), path.clone()).with_frame(frame);
+            let graph = MeshGraph::<E3>::from_raw_buffers(
+                sweep.indexing_polygons::<Position>(),
+                sweep.vertices::<Position<E3>>(),
+            )
+            .unwrap();
+            // The profile is neither twisted nor scaled at the ends of the
+            // path.
+            let positions = sweep.vertices::<Position<E3>>().collect::<Vec<_>>();
+            assert!(((positions[0] - positions[2]).norm() - 2.0f64.sqrt()).abs() < 1e-9);
+            assert!(graph.volume().unwrap() > 0.0);
+        }
+    }
+
+    #[test]
+    fn lathe_cylinder() {
+        let lathe = Lathe::new(vec![(0.0, -1.0), (1.0, -1.0), (1.0, 1.0), (0.0, 1.0)], 8);
+        // Two poles and two rings.
+        assert_eq!(18, lathe.vertices::<Position<E3>>().count());
+        assert_eq!(24, lathe.polygons::<Position<E3>>().count());
+        let graph = MeshGraph::<E3>::from_raw_buffers(
+            lathe.indexing_polygons::<Position>(),
+            lathe.vertices::<Position<E3>>(),
+        )
+        .unwrap();
+        // The volume of an octagonal prism.
+        let expected = 2.0 * 8.0 * 0.5 * (2.0 * PI / 8.0).sin();
+        assert!((graph.volume().unwrap() - expected).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,350 @@
+//! Torus primitives.
+//!
+//! # Examples
+//!
+//! Generating a graph from the positional data of a torus:
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use nalgebra::Point3;
+//! use plexus::graph::MeshGraph;
+//! use plexus::prelude::*;
+//! use plexus::primitive::generate::Position;
+//! use plexus::primitive::torus::Torus;
+//!
+//! type E3 = Point3<f64>;
+//!
+//! let torus = Torus::new(32, 16);
+//! let graph = MeshGraph::<E3>::from_raw_buffers(
+//!     torus.indexing_polygons::<Position>(),
+//!     torus.vertices::<Position<E3>>(),
+//! )
+//! .unwrap();
+//! ```
+
+use decorum::Real;
+use num::{One, Zero};
+use std::cmp;
+use theon::adjunct::Map;
+use theon::ops::{Cross, Dot};
+use theon::query::Unit;
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use typenum::U3;
+
+use crate::primitive::generate::{
+    AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
+    IndexingPolygonGenerator, Normal, PolygonGenerator, Position,
+};
+use crate::primitive::{into_scalar, Tetragon};
+
+#[derive(Clone, Copy)]
+pub struct Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    major: Scalar<S>,
+    minor: Scalar<S>,
+}
+
+impl<S> Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    /// Creates bounds with the radius of the center of the tube (`major`) and
+    /// the radius of the tube (`minor`).
+    pub fn with_radii(major: Scalar<S>, minor: Scalar<S>) -> Self {
+        Bounds { major, minor }
+    }
+}
+
+impl<S> Default for Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    fn default() -> Self {
+        Self::with_radii(One::one(), into_scalar::<_, S>(0.25))
+    }
+}
+
+/// Torus or torus knot.
+///
+/// A torus is a tube about a circle in the $xy$ plane. When configured as a
+/// $(p, q)$ torus knot via [`Torus::with_knot`], the tube instead follows a
+/// curve that winds $p$ times about the $z$ axis and $q$ times through the
+/// hole of a torus. The knot lies on a torus with a major radius of the
+/// `major` radius of the [`Bounds`] and a minor radius of half of that.
+#[derive(Clone, Copy)]
+pub struct Torus {
+    nu: usize, // Segments along the tube.
+    nv: usize, // Segments about the tube.
+    knot: Option<(usize, usize)>,
+}
+
+impl Torus {
+    pub fn new(nu: usize, nv: usize) -> Self {
+        Torus {
+            nu: cmp::max(3, nu),
+            nv: cmp::max(3, nv),
+            knot: None,
+        }
+    }
+
+    /// Winds the tube along a $(p, q)$ torus knot.
+    ///
+    /// If $p$ and $q$ are not coprime, then the curve is traced more than once
+    /// and the tube intersects itself. Knots typically require many more
+    /// segments along the tube than tori.
+    pub fn with_knot(mut self, p: usize, q: usize) -> Self {
+        self.knot = Some((cmp::max(1, p), q));
+        self
+    }
+
+    // Gets the center and normal of the tube at `(u, v)`.
+    fn vertex_with_frame_from<S>(&self, state: &Bounds<S>, u: usize, v: usize) -> (S, Vector<S>)
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<S>: Cross<Output = Vector<S>>,
+    {
+        let zero = Scalar::<S>::zero();
+        let one = Scalar::<S>::one();
+        let tau = Real::PI * (one + one);
+        let t = (into_scalar::<_, S>(u % self.nu) / into_scalar::<_, S>(self.nu)) * tau;
+        let phi = (into_scalar::<_, S>(v % self.nv) / into_scalar::<_, S>(self.nv)) * tau;
+        let (center, n, b) = match self.knot {
+            None => {
+                let (c, s) = (t.cos(), t.sin());
+                (
+                    S::from_xyz(state.major * c, state.major * s, zero),
+                    S::from_xyz(c, s, zero) - S::origin(),
+                    S::from_xyz(zero, zero, one) - S::origin(),
+                )
+            }
+            Some((p, q)) => {
+                let (p, q) = (into_scalar::<_, S>(p), into_scalar::<_, S>(q));
+                let a = state.major / (one + one);
+                let (cp, sp) = ((p * t).cos(), (p * t).sin());
+                let (cq, sq) = ((q * t).cos(), (q * t).sin());
+                let rho = state.major + (a * cq);
+                let center = S::from_xyz(rho * cp, rho * sp, -a * sq);
+                let drho = -a * q * sq;
+                let tangent = (S::from_xyz(
+                    (drho * cp) - (rho * p * sp),
+                    (drho * sp) + (rho * p * cp),
+                    -a * q * cq,
+                ) - S::origin())
+                .normalize()
+                .expect("non-zero vector");
+                // The normal points from the circle at the center of the
+                // torus on which the knot lies toward the knot, so the tube
+                // does not twist about the curve.
+                let d = center - S::from_xyz(state.major * cp, state.major * sp, zero);
+                let n = (d - (tangent * d.dot(tangent)))
+                    .normalize()
+                    .expect("non-zero vector");
+                (center, n, n.cross(tangent))
+            }
+        };
+        (center, (n * phi.cos()) + (b * phi.sin()))
+    }
+
+    fn vertex_with_position_from<S>(&self, state: &Bounds<S>, u: usize, v: usize) -> S
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<S>: Cross<Output = Vector<S>>,
+    {
+        let (center, normal) = self.vertex_with_frame_from(state, u, v);
+        center + (normal * state.minor)
+    }
+
+    fn vertex_with_normal_from<S>(&self, u: usize, v: usize) -> Unit<Vector<S>>
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<S>: Cross<Output = Vector<S>>,
+    {
+        let (_, normal) = self.vertex_with_frame_from::<S>(&Default::default(), u, v);
+        Unit::try_from_inner(normal).expect("non-zero vector")
+    }
+
+    fn index_for_position(&self, u: usize, v: usize) -> usize {
+        ((u % self.nu) * self.nv) + (v % self.nv)
+    }
+
+    fn map_vertex_index(&self, index: usize) -> (usize, usize) {
+        (index / self.nv, index % self.nv)
+    }
+}
+
+impl Default for Torus {
+    fn default() -> Self {
+        Torus::new(32, 16)
+    }
+}
+
+impl PolygonGenerator for Torus {
+    fn polygon_count(&self) -> usize {
+        self.nu * self.nv
+    }
+}
+
+impl<S> AttributeGenerator<Normal<S>> for Torus
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<Normal<S>> for Torus
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    type Output = Unit<Vector<S>>;
+
+    fn vertex_count(&self) -> usize {
+        self.nu * self.nv
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        let (u, v) = self.map_vertex_index(index);
+        self.vertex_with_normal_from::<S>(u, v)
+    }
+}
+
+impl<S> AttributePolygonGenerator<Normal<S>> for Torus
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    type Output = Tetragon<Unit<Vector<S>>>;
+
+    fn polygon_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Normal<S>>::indexing_polygon(self, index).map(|index| {
+            let (u, v) = self.map_vertex_index(index);
+            self.vertex_with_normal_from::<S>(u, v)
+        })
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Normal<S>> for Torus {
+    type Output = Tetragon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Position<S>>::indexing_polygon(self, index)
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for Torus
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for Torus
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        self.nu * self.nv
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        let (u, v) = self.map_vertex_index(index);
+        self.vertex_with_position_from(state, u, v)
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for Torus
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    type Output = Tetragon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        // As with indexing, `(u, v)` wraps so that positions are computed
+        // from exactly `nu * nv` unique values.
+        IndexingPolygonGenerator::<Position<S>>::indexing_polygon(self, index).map(|index| {
+            let (u, v) = self.map_vertex_index(index);
+            self.vertex_with_position_from(state, u, v)
+        })
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Position<S>> for Torus {
+    type Output = Tetragon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        let (u, v) = (index / self.nv, index % self.nv);
+        Tetragon::new(
+            self.index_for_position(u, v),
+            self.index_for_position(u + 1, v),
+            self.index_for_position(u + 1, v + 1),
+            self.index_for_position(u, v + 1),
+        )
+    }
+}
+
+impl Generator for Torus {}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+    use std::collections::BTreeSet;
+    use std::f64::consts::PI;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::generate::{Normal, Position};
+    use crate::primitive::torus::{Bounds, Torus};
+
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn vertex_and_polygon_count() {
+        let torus = Torus::new(8, 4);
+        assert_eq!(32, torus.vertices::<Position<E3>>().count());
+        assert_eq!(32, torus.polygons::<Position<E3>>().count());
+        assert_eq!(
+            32,
+            torus
+                .indexing_polygons::<Position>()
+                .vertices()
+                .collect::<BTreeSet<_>>()
+                .len()
+        );
+    }
+
+    #[test]
+    fn torus_volume() {
+        let torus = Torus::new(64, 32);
+        let graph = MeshGraph::<E3>::from_raw_buffers(
+            torus.indexing_polygons::<Position>(),
+            torus.vertices_from::<Position<E3>>(Bounds::with_radii(1.0, 0.25)),
+        )
+        .unwrap();
+        let expected = 2.0 * PI * PI * 0.25 * 0.25;
+        let volume = graph.volume().unwrap();
+        assert!(((volume - expected) / expected).abs() < 0.02);
+        assert!(torus
+            .vertices::<Normal<E3>>()
+            .all(|normal| (normal.into_inner().norm() - 1.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn knot_is_closed() {
+        let torus = Torus::new(128, 8).with_knot(2, 3);
+        let graph = MeshGraph::<E3>::from_raw_buffers(
+            torus.indexing_polygons::<Position>(),
+            torus.vertices_from::<Position<E3>>(Bounds::with_radii(1.0, 0.1)),
+        )
+        .unwrap();
+        assert_eq!(128 * 8, graph.vertex_count());
+        assert!(graph.volume().unwrap() > 0.0);
+    }
+}
@@ -0,0 +1,361 @@
+//! Triangulation of polygons with holes.
+//!
+//! The [`Triangulate`] and [`IntoTrigons`] decompositions fan polygons about a
+//! vertex and so only produce correct results for convex polygons. This module
+//! triangulates arbitrary simple polygons in $\Reals^2$, which may be concave
+//! and may contain holes, using ear clipping. Holes are joined to the outer
+//! boundary of the polygon with bridging edges before ears are clipped.
+//!
+//! Triangulations index the vertices of the outer boundary followed by the
+//! vertices of each hole, which are also returned in that order. This data
+//! can be used directly as raw buffers for [`MeshBuffer3`].
+//!
+//! # Examples
+//!
+//! Triangulating a square with a square hole:
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use nalgebra::Point2;
+//! use plexus::buffer::MeshBuffer3;
+//! use plexus::prelude::*;
+//! use plexus::primitive::triangulate;
+//! use plexus::primitive::UnboundedPolygon;
+//!
+//! type E2 = Point2<f64>;
+//!
+//! let outer = UnboundedPolygon::tetragon(
+//!     E2::new(-2.0, -2.0),
+//!     E2::new(2.0, -2.0),
+//!     E2::new(2.0, 2.0),
+//!     E2::new(-2.0, 2.0),
+//! );
+//! let hole = UnboundedPolygon::tetragon(
+//!     E2::new(-1.0, -1.0),
+//!     E2::new(-1.0, 1.0),
+//!     E2::new(1.0, 1.0),
+//!     E2::new(1.0, -1.0),
+//! );
+//! let (indices, vertices) = triangulate::triangulate_with_holes(outer, Some(hole)).unwrap();
+//! let buffer = MeshBuffer3::<usize, E2>::from_raw_buffers(indices, vertices).unwrap();
+//! ```
+//!
+//! [`MeshBuffer3`]: crate::buffer::MeshBuffer3
+//! [`IntoTrigons`]: crate::primitive::decompose::IntoTrigons
+//! [`Triangulate`]: crate::primitive::decompose::Triangulate
+
+use num::Zero;
+use std::cmp::Ordering;
+use std::iter;
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar};
+use theon::{AsPosition, Position};
+use typenum::U2;
+
+use crate::primitive::{is_point_in_triangle, orientation, shoelace, Trigon, UnboundedPolygon};
+
+/// Triangulates a simple polygon in $\Reals^2$.
+///
+/// See [`triangulate_with_holes`].
+///
+/// [`triangulate_with_holes`]: crate::primitive::triangulate::triangulate_with_holes
+pub fn triangulate<G>(polygon: UnboundedPolygon<G>) -> Option<(Vec<Trigon<usize>>, Vec<G>)>
+where
+    G: AsPosition,
+    Position<G>: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    triangulate_with_holes(polygon, iter::empty())
+}
+
+/// Triangulates a simple polygon in $\Reals^2$ with holes.
+///
+/// Returns triangles that index the vertices of `outer` followed by the
+/// vertices of each of the `holes`. Triangles are wound counter-clockwise
+/// regardless of the winding of the input polygons.
+///
+/// Holes must lie within `outer` and must not intersect `outer` or each other.
+///
+/// Returns `None` if the polygon cannot be triangulated, which typically
+/// occurs if the input is not simple (i.e., its edges intersect).
+pub fn triangulate_with_holes<G, I>(
+    outer: UnboundedPolygon<G>,
+    holes: I,
+) -> Option<(Vec<Trigon<usize>>, Vec<G>)>
+where
+    G: AsPosition,
+    Position<G>: EuclideanSpace + FiniteDimensional<N = U2>,
+    I: IntoIterator<Item = UnboundedPolygon<G>>,
+{
+    let mut vertices = Vec::new();
+    let mut ring = |polygon: UnboundedPolygon<G>, is_counter_clockwise: bool| {
+        let offset = vertices.len();
+        vertices.extend(polygon);
+        let mut ring = (offset..vertices.len()).collect::<Vec<_>>();
+        let points = ring
+            .iter()
+            .map(|index| vertices[*index].as_position().into_xy())
+            .collect::<Vec<_>>();
+        if (shoelace::<Position<G>>(&points) > Zero::zero()) != is_counter_clockwise {
+            ring.reverse();
+        }
+        ring
+    };
+    // The outer boundary is wound counter-clockwise and holes are wound
+    // clockwise, so that the interior of the polygon is always on the left.
+    let mut outer = ring(outer, true);
+    let holes = holes
+        .into_iter()
+        .map(|hole| ring(hole, false))
+        .collect::<Vec<_>>();
+    let points = vertices
+        .iter()
+        .map(|vertex| vertex.as_position().into_xy())
+        .collect::<Vec<_>>();
+    bridge::<Position<G>>(&points, &mut outer, holes)?;
+    let trigons = clip::<Position<G>>(&points, outer)?;
+    Some((trigons, vertices))
+}
+
+type Point<S> = (Scalar<S>, Scalar<S>);
+
+// Joins holes to the outer boundary. Holes are joined in order of their
+// rightmost vertices, from right to left, by casting a ray in the positive $x$
+// direction from the rightmost vertex of each hole to find a visible vertex of
+// the boundary. The hole is then spliced into the boundary along a bridging
+// edge between these vertices, traversing the bridge in both directions.
+fn bridge<S>(points: &[Point<S>], outer: &mut Vec<usize>, mut holes: Vec<Vec<usize>>) -> Option<()>
+where
+    S: EuclideanSpace,
+{
+    let rightmost = |hole: &[usize]| {
+        (0..hole.len())
+            .max_by(|a, b| compare(points[hole[*a]].0, points[hole[*b]].0))
+            .expect("empty hole")
+    };
+    holes.retain(|hole| hole.len() >= 3);
+    holes.sort_by(|a, b| compare(points[b[rightmost(b)]].0, points[a[rightmost(a)]].0));
+    for hole in holes {
+        let m = rightmost(&hole);
+        let target = visible_vertex::<S>(points, outer, points[hole[m]])?;
+        let spliced = outer[..=target]
+            .iter()
+            .chain(hole[m..].iter())
+            .chain(hole[..=m].iter())
+            .chain(outer[target..].iter())
+            .copied()
+            .collect();
+        *outer = spliced;
+    }
+    Some(())
+}
+
+// Finds the position in the boundary of a vertex that is visible from the
+// given point, which must be in the interior of the boundary.
+fn visible_vertex<S>(points: &[Point<S>], outer: &[usize], m: Point<S>) -> Option<usize>
+where
+    S: EuclideanSpace,
+{
+    let n = outer.len();
+    let point = |position: usize| points[outer[position % n]];
+    // Find the nearest edge that crosses the ray from `m`. The interior is to
+    // the left of the boundary, so these edges are directed upward.
+    let mut nearest: Option<(Scalar<S>, usize)> = None;
+    for position in 0..n {
+        let (a, b) = (point(position), point(position + 1));
+        if a.1 <= m.1 && m.1 <= b.1 && a.1 != b.1 {
+            let x = a.0 + ((m.1 - a.1) * (b.0 - a.0) / (b.1 - a.1));
+            if x >= m.0 && nearest.map_or(true, |(nearest, _)| x < nearest) {
+                // Use the endpoint of the edge with the greatest $x$.
+                let position = if a.0 > b.0 { position } else { (position + 1) % n };
+                nearest = Some((x, position));
+            }
+        }
+    }
+    let (x, candidate) = nearest?;
+    let i = (x, m.1);
+    let p = point(candidate);
+    // Other vertices of the boundary may lie within the triangle formed by
+    // `m`, the intersection `i`, and the candidate `p`, in which case they
+    // obstruct `p`. Choose the vertex in this triangle that forms the least
+    // angle with the ray, as it is visible from `m`.
+    let triangle = [m, i, p];
+    (0..n)
+        .filter(|position| {
+            let r = point(*position);
+            r.0 >= m.0 && (r == p || is_point_in_triangle(r, &triangle))
+        })
+        .filter(|position| {
+            let (q, r, s) = (point(*position + n - 1), point(*position), point(*position + 1));
+            is_locally_inside::<S>(q, r, s, m)
+        })
+        .map(|position| {
+            let r = point(position);
+            let dy = if r.1 < m.1 { m.1 - r.1 } else { r.1 - m.1 };
+            let dx = r.0 - m.0;
+            (position, dy, dx)
+        })
+        .min_by(|(_, dy1, dx1), (_, dy2, dx2)| {
+            // Compare the tangents of the angles without division.
+            compare(*dy1 * *dx2, *dy2 * *dx1).then_with(|| compare(*dx1, *dx2))
+        })
+        .map(|(position, _, _)| position)
+}
+
+// Determines if the direction from the vertex `r` to the point `m` is within
+// the interior angle at `r`, where `q` and `s` are the previous and next
+// vertices in a counter-clockwise boundary.
+fn is_locally_inside<S>(q: Point<S>, r: Point<S>, s: Point<S>, m: Point<S>) -> bool
+where
+    S: EuclideanSpace,
+{
+    let zero = Scalar::<S>::zero();
+    if orientation(q, r, s) >= zero {
+        orientation(r, s, m) > zero && orientation(r, m, q) > zero
+    }
+    else {
+        orientation(r, s, m) > zero || orientation(r, m, q) > zero
+    }
+}
+
+// Clips ears from a counter-clockwise boundary until only a triangle remains.
+fn clip<S>(points: &[Point<S>], mut ring: Vec<usize>) -> Option<Vec<Trigon<usize>>>
+where
+    S: EuclideanSpace,
+{
+    let zero = Scalar::<S>::zero();
+    let mut trigons = Vec::with_capacity(ring.len().saturating_sub(2));
+    let mut position = 0;
+    let mut remaining = ring.len();
+    while ring.len() > 3 {
+        let n = ring.len();
+        position %= n;
+        let (a, b, c) = (ring[(position + n - 1) % n], ring[position], ring[(position + 1) % n]);
+        if is_ear::<S>(points, &ring, (a, b, c)) {
+            trigons.push(Trigon::new(a, b, c));
+            ring.remove(position);
+            remaining = ring.len();
+        }
+        else {
+            position += 1;
+            remaining -= 1;
+        }
+        if remaining == 0 {
+            // No ears were found in a full traversal of the boundary. Remove a
+            // degenerate vertex that is collinear with its neighbors if there
+            // is one, as it forms no triangle. Otherwise, the boundary is not
+            // simple.
+            let n = ring.len();
+            let degenerate = (0..n).find(|position| {
+                let (a, b, c) = (
+                    ring[(position + n - 1) % n],
+                    ring[*position],
+                    ring[(position + 1) % n],
+                );
+                orientation(points[a], points[b], points[c]) == zero
+            })?;
+            ring.remove(degenerate);
+            remaining = ring.len();
+        }
+    }
+    if let [a, b, c] = ring[..] {
+        if orientation(points[a], points[b], points[c]) > zero {
+            trigons.push(Trigon::new(a, b, c));
+        }
+    }
+    Some(trigons)
+}
+
+// Determines if the triangle `(a, b, c)` formed by consecutive vertices of the
+// boundary is an ear, meaning that it is convex and contains no other vertices
+// of the boundary. Vertices that are duplicated by bridges are distinguished by
+// their index.
+fn is_ear<S>(points: &[Point<S>], ring: &[usize], (a, b, c): (usize, usize, usize)) -> bool
+where
+    S: EuclideanSpace,
+{
+    let triangle = [points[a], points[b], points[c]];
+    orientation(triangle[0], triangle[1], triangle[2]) > Zero::zero()
+        && !ring
+            .iter()
+            .filter(|index| **index != a && **index != b && **index != c)
+            .any(|index| is_point_in_triangle(points[*index], &triangle))
+}
+
+fn compare<T>(a: T, b: T) -> Ordering
+where
+    T: PartialOrd,
+{
+    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point2;
+
+    use crate::primitive::triangulate::{triangulate, triangulate_with_holes};
+    use crate::primitive::{Trigon, UnboundedPolygon};
+    use crate::IteratorExt as _;
+
+    type E2 = Point2<f64>;
+
+    fn area(trigons: &[Trigon<usize>], vertices: &[E2]) -> f64 {
+        trigons
+            .iter()
+            .map(|trigon| {
+                let [a, b, c] = trigon.into_array().map(|index| vertices[index]);
+                ((b - a).perp(&(c - a))) / 2.0
+            })
+            .sum()
+    }
+
+    #[test]
+    fn triangulate_concave() {
+        // An L-shaped polygon wound clockwise.
+        let polygon: UnboundedPolygon<_> = vec![
+            E2::new(0.0, 0.0),
+            E2::new(0.0, 2.0),
+            E2::new(1.0, 2.0),
+            E2::new(1.0, 1.0),
+            E2::new(2.0, 1.0),
+            E2::new(2.0, 0.0),
+        ]
+        .into_iter()
+        .try_collect()
+        .unwrap();
+        let (trigons, vertices) = triangulate(polygon).unwrap();
+        assert_eq!(4, trigons.len());
+        // Triangles are wound counter-clockwise, so all areas are positive.
+        assert!((area(&trigons, &vertices) - 3.0).abs() < 1e-9);
+        assert!(trigons
+            .iter()
+            .all(|trigon| area(&[*trigon], &vertices) > 0.0));
+    }
+
+    #[test]
+    fn triangulate_with_two_holes() {
+        let outer = UnboundedPolygon::tetragon(
+            E2::new(0.0, 0.0),
+            E2::new(6.0, 0.0),
+            E2::new(6.0, 3.0),
+            E2::new(0.0, 3.0),
+        );
+        let hole = |x: f64| {
+            UnboundedPolygon::tetragon(
+                E2::new(x, 1.0),
+                E2::new(x + 1.0, 1.0),
+                E2::new(x + 1.0, 2.0),
+                E2::new(x, 2.0),
+            )
+        };
+        let (trigons, vertices) =
+            triangulate_with_holes(outer, vec![hole(1.0), hole(4.0)]).unwrap();
+        assert_eq!(12, vertices.len());
+        // A polygon with $n$ vertices and $h$ holes has $n + 2h - 2$ triangles.
+        assert_eq!(14, trigons.len());
+        assert!((area(&trigons, &vertices) - 16.0).abs() < 1e-9);
+        assert!(trigons
+            .iter()
+            .all(|trigon| area(&[*trigon], &vertices) > 0.0));
+    }
+}